@@ -0,0 +1,127 @@
+//! ## Length-bounded wrapper types
+//!
+//! These newtypes carry their length bounds as const generics, so the same constraint
+//! can be reused across fields without repeating the attribute:
+//!
+//! ```rust
+//! use garde::bounded::BoundedString;
+//! use garde::Validate;
+//!
+//! type Username = BoundedString<3, 16>;
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(dive)]
+//!     name: Username,
+//! }
+//!
+//! let user = User { name: Username::new("ab".into()) };
+//! assert!(user.validate().is_err());
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::error::Path;
+use crate::rules::length::{bytes, simple};
+use crate::{Report, Validate};
+
+/// A `String` whose length in bytes must be within `MIN..=MAX`.
+///
+/// This is equivalent to `#[garde(length(bytes, min = MIN, max = MAX))]`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BoundedString<const MIN: usize, const MAX: usize>(String);
+
+impl<const MIN: usize, const MAX: usize> BoundedString<MIN, MAX> {
+    /// Rejects `MIN > MAX` at compile time, wherever the type is constructed or validated.
+    const BOUNDS_CHECK: () = assert!(MIN <= MAX, "`MIN` must not be greater than `MAX`");
+
+    /// Wraps `value`. The bounds are only checked on validation.
+    pub fn new(value: String) -> Self {
+        let () = Self::BOUNDS_CHECK;
+        Self(value)
+    }
+
+    /// Unwraps the inner `String`.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Deref for BoundedString<MIN, MAX> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<String> for BoundedString<MIN, MAX> {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Validate for BoundedString<MIN, MAX> {
+    type Context = ();
+
+    fn validate_into(&self, _: &(), parent: &mut dyn FnMut() -> Path, report: &mut Report) {
+        let () = Self::BOUNDS_CHECK;
+        if let Err(error) = bytes::apply(&self.0, (MIN, MAX)) {
+            report.append(parent(), error);
+        }
+    }
+}
+
+/// A `Vec<T>` whose number of elements must be within `MIN..=MAX`.
+///
+/// This is equivalent to `#[garde(length(min = MIN, max = MAX))]`.
+/// The elements themselves are not validated.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BoundedVec<T, const MIN: usize, const MAX: usize>(Vec<T>);
+
+impl<T, const MIN: usize, const MAX: usize> BoundedVec<T, MIN, MAX> {
+    /// Rejects `MIN > MAX` at compile time, wherever the type is constructed or validated.
+    const BOUNDS_CHECK: () = assert!(MIN <= MAX, "`MIN` must not be greater than `MAX`");
+
+    /// Wraps `value`. The bounds are only checked on validation.
+    pub fn new(value: Vec<T>) -> Self {
+        let () = Self::BOUNDS_CHECK;
+        Self(value)
+    }
+
+    /// Unwraps the inner `Vec`.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BoundedVec<T, MIN, MAX> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<Vec<T>> for BoundedVec<T, MIN, MAX> {
+    fn from(value: Vec<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Validate for BoundedVec<T, MIN, MAX> {
+    type Context = ();
+
+    fn validate_into(&self, _: &(), parent: &mut dyn FnMut() -> Path, report: &mut Report) {
+        let () = Self::BOUNDS_CHECK;
+        if let Err(error) = simple::apply(&self.0, (MIN, MAX)) {
+            report.append(parent(), error);
+        }
+    }
+}
@@ -3,10 +3,12 @@
 //! The entrypoint of this module is the [`Error`] type.
 #![allow(dead_code)]
 
+pub mod messages;
 mod rc_list;
 
 use alloc::borrow::Cow;
-use alloc::string::String;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use compact_str::{CompactString, ToCompactString};
 use smallvec::SmallVec;
@@ -20,47 +22,202 @@ use self::rc_list::List;
 /// A single field or list item may have any number of errors attached to it.
 ///
 /// It is possible to extract all errors for specific field using the [`select`][`crate::select`] macro.
+///
+/// With the `serde` feature, a report is serialized as a list of errors, each with its path in display form:
+/// ```json
+/// [{ "path": "address.zip", "kind": "length_out_of_range", "message": "length 6 is greater than maximum 5", "length": 6 }]
+/// ```
 #[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
     errors: Vec<(Path, Error)>,
+    first_only: bool,
 }
 
 impl Report {
     /// Create an empty [`Report`].
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            first_only: false,
+        }
+    }
+
+    /// Create an empty [`Report`] with room for at least `capacity` errors before reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            errors: Vec::with_capacity(capacity),
+            first_only: false,
+        }
+    }
+
+    /// The number of errors this report can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.errors.capacity()
+    }
+
+    /// Create an empty [`Report`] which only keeps the first [`Error`] appended to it.
+    ///
+    /// Derived [`Validate`][crate::Validate] implementations stop evaluating rules
+    /// as soon as such a report holds an error.
+    pub fn first_only() -> Self {
+        Self {
+            errors: Vec::new(),
+            first_only: true,
+        }
     }
 
     /// Append an [`Error`] into this report at the given [`Path`].
     pub fn append(&mut self, path: Path, error: Error) {
+        if self.__is_done() || (self.first_only && error.is_warning()) {
+            return;
+        }
         self.errors.push((path, error));
     }
 
-    /// Iterate over all `(Path, Error)` pairs.
+    /// Used by the derive to stop validation early, see [`Report::first_only`].
+    #[doc(hidden)]
+    pub fn __is_done(&self) -> bool {
+        self.first_only && !self.errors.is_empty()
+    }
+
+    /// Iterate over all `(Path, Error)` pairs, including warnings.
     pub fn iter(&self) -> impl Iterator<Item = &(Path, Error)> {
         self.errors.iter()
     }
 
-    /// Returns `true` if the report contains no validation errors.
+    /// Iterate over the `(Path, Error)` pairs with [`Severity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &(Path, Error)> {
+        self.errors.iter().filter(|(_, error)| !error.is_warning())
+    }
+
+    /// Iterate over the `(Path, Error)` pairs with [`Severity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &(Path, Error)> {
+        self.errors.iter().filter(|(_, error)| error.is_warning())
+    }
+
+    /// Render each error on its own line, as its path followed by `separator` and the message,
+    /// e.g. `addresses[1].zip: length 6 is greater than maximum 5`.
+    ///
+    /// Errors at the root are rendered without a path. The [`Display`][core::fmt::Display]
+    /// implementation of [`Report`] uses `": "` as the separator.
+    pub fn display_with_paths<'a>(&'a self, separator: &'a str) -> DisplayWithPaths<'a> {
+        DisplayWithPaths {
+            report: self,
+            separator,
+        }
+    }
+
+    /// Returns `true` if the report contains no errors.
+    ///
+    /// Warnings do not make a report invalid, unlike for [`Report::is_empty`].
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Returns `true` if the report contains no entries at all, neither errors nor warnings.
+    ///
+    /// Use [`Report::is_valid`] to check whether validation passed.
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
 
+    /// Returns the number of validation errors and warnings.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Removes all validation errors, keeping the allocated capacity.
+    ///
+    /// Together with [`Validate::validate_into`][crate::Validate::validate_into],
+    /// this allows a single report to be reused across many validations.
+    pub fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    /// Returns `Ok(())` if the report [is valid][Report::is_valid], and `Err(self)` otherwise.
+    ///
+    /// Warnings alone do not cause an `Err`, and are dropped when the report is valid.
+    /// An `Err` keeps them, so they are included in its [`Display`][core::fmt::Display] output and [`Report::iter`].
+    pub fn into_result(self) -> Result<(), Report> {
+        match self.is_valid() {
+            true => Ok(()),
+            false => Err(self),
+        }
+    }
+
+    /// Append every entry of `other` into this report, nesting its paths under `prefix`.
+    ///
+    /// This is useful when validating several values by hand,
+    /// for example with [`Validate::validate`][crate::Validate::validate], and combining their reports.
+    ///
+    /// ```rust
+    /// use garde::{Error, Path, Report};
+    ///
+    /// let mut address = Report::new();
+    /// address.append(Path::new("zip"), Error::new("invalid zip code"));
+    ///
+    /// let mut report = Report::new();
+    /// report.merge("address", address);
+    /// assert_eq!(report.to_string(), "address.zip: invalid zip code\n");
+    /// ```
+    pub fn merge(&mut self, prefix: &str, other: Report) {
+        for (path, error) in other.errors {
+            self.append(path.prefixed(prefix), error);
+        }
+    }
+
     /// Converts into the inner validation errors.
     pub fn into_inner(self) -> Vec<(Path, Error)> {
         self.errors
     }
+
+    /// Converts into a map from each path, in its display form, to the messages of its errors and warnings.
+    ///
+    /// Messages under the same path keep the order in which they were appended.
+    ///
+    /// ```rust
+    /// use garde::error::{Error, Path};
+    /// use garde::Report;
+    ///
+    /// let mut report = Report::new();
+    /// report.append(Path::new("name"), Error::new("too short"));
+    /// report.append(Path::new("name"), Error::new("not ascii"));
+    ///
+    /// let grouped = report.into_grouped();
+    /// assert_eq!(grouped["name"], ["too short", "not ascii"]);
+    /// ```
+    pub fn into_grouped(self) -> BTreeMap<String, Vec<String>> {
+        let mut grouped = BTreeMap::<String, Vec<String>>::new();
+        for (path, error) in self.errors {
+            grouped
+                .entry(path.to_string())
+                .or_default()
+                .push(error.message.into());
+        }
+        grouped
+    }
 }
 
 impl core::fmt::Display for Report {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for (path, error) in self.iter() {
+        core::fmt::Display::fmt(&self.display_with_paths(": "), f)
+    }
+}
+
+/// Renders a [`Report`] with one line per error, see [`Report::display_with_paths`].
+pub struct DisplayWithPaths<'a> {
+    report: &'a Report,
+    separator: &'a str,
+}
+
+impl core::fmt::Display for DisplayWithPaths<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (path, error) in self.report.iter() {
             if path.is_empty() {
                 writeln!(f, "{error}")?;
             } else {
-                writeln!(f, "{path}: {error}")?;
+                writeln!(f, "{path}{}{error}", self.separator)?;
             }
         }
         Ok(())
@@ -72,19 +229,234 @@ impl core::error::Error for Report {}
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
+    #[cfg_attr(feature = "serde", serde(default))]
+    kind: ErrorKind,
     message: CompactString,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")
+    )]
+    code: Option<&'static str>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    length: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Severity::is_error")
+    )]
+    severity: Severity,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_deserializing, skip_serializing_if = "str::is_empty")
+    )]
+    rule: &'static str,
+}
+
+/// Whether an [`Error`] fails validation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Severity {
+    /// Fails validation.
+    #[default]
+    Error,
+    /// Is collected into the [`Report`], but does not fail validation.
+    Warning,
+}
+
+impl Severity {
+    fn is_error(&self) -> bool {
+        *self == Severity::Error
+    }
 }
 
 impl Error {
+    /// Create an [`Error`] of kind [`ErrorKind::Custom`].
     pub fn new(message: impl ToCompactString) -> Self {
+        Self::with_kind(ErrorKind::Custom, message)
+    }
+
+    /// Create an [`Error`] of the given [`ErrorKind`].
+    pub fn with_kind(kind: ErrorKind, message: impl ToCompactString) -> Self {
         Self {
+            kind,
             message: message.to_compact_string(),
+            code: None,
+            length: None,
+            severity: Severity::Error,
+            rule: "",
         }
     }
 
+    /// Attach a machine-readable code to this error.
+    ///
+    /// The code is independent of the message, which may be changed or localized freely.
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Attach the measured length of the value which failed validation.
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Prefix the message with `context`, e.g. `"while parsing header: <message>"`.
+    ///
+    /// The kind, code, length, severity and rule are kept.
+    pub fn with_context(mut self, context: &str) -> Self {
+        self.message = compact_str::format_compact!("{context}: {}", self.message);
+        self
+    }
+
+    /// Replace the message of this error.
+    ///
+    /// The kind, code, length, severity and rule are kept.
+    pub fn with_message(mut self, message: impl ToCompactString) -> Self {
+        self.message = message.to_compact_string();
+        self
+    }
+
+    /// Set the name of the rule which produced this error, see [`Error::rule`].
+    pub fn with_rule(mut self, rule: &'static str) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the [`Severity`] of this error.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns `true` if this error has [`Severity::Warning`].
+    pub fn is_warning(&self) -> bool {
+        self.severity == Severity::Warning
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    /// The name of the rule which produced this error, e.g. `"email"` or `"range"`.
+    ///
+    /// The `length` rule reports its mode instead, e.g. `"bytes"` or `"chars"`,
+    /// and `"length"` for the default mode. This is empty for errors created via
+    /// [`Error::new`] or [`Error::with_kind`], unless set with [`Error::with_rule`].
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// The measured length, set by the `length` rules.
+    ///
+    /// This is `None` when the rule stopped counting early, such as `chars` on a value far over its maximum.
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+}
+
+/// The kind of check which produced an [`Error`].
+///
+/// Built-in rules always produce their own kind, errors created via [`Error::new`] are [`ErrorKind::Custom`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ErrorKind {
+    /// `length` in the default `simple` mode.
+    LengthOutOfRange,
+    /// `length(bytes)`.
+    BytesOutOfRange,
+    /// `length(chars)`.
+    CharsOutOfRange,
+    /// `length(graphemes)`.
+    GraphemesOutOfRange,
+    /// `length(utf16)`.
+    Utf16OutOfRange,
+    /// `length(words)`.
+    WordsOutOfRange,
+    /// `length(lines)`.
+    LinesOutOfRange,
+    /// `length(count)`.
+    CountOutOfRange,
+    /// `length(split = ..)`.
+    SegmentsOutOfRange,
+    /// `length(base64_decoded)`.
+    Base64DecodedOutOfRange,
+    /// `range`.
+    OutOfRange,
+    /// `char_range`.
+    CharOutOfRange,
+    /// `bits`.
+    TooManyBits,
+    /// `multiple_of`.
+    NotMultipleOf,
+    /// `ascii`.
+    NotAscii,
+    /// `alphanumeric`.
+    NotAlphanumeric,
+    /// `ascii_alphanumeric`.
+    NotAsciiAlphanumeric,
+    /// `lowercase`.
+    NotLowercase,
+    /// `uppercase`.
+    NotUppercase,
+    /// `contains`.
+    MissingSubstring,
+    /// `not_contains`.
+    ForbiddenSubstring,
+    /// `prefix` and `prefix_any`.
+    MissingPrefix,
+    /// `suffix` and `suffix_any`.
+    MissingSuffix,
+    /// `one_of`.
+    NotOneOf,
+    /// `pattern`.
+    PatternMismatch,
+    /// `matches`.
+    FieldMismatch,
+    /// `required`.
+    NotSet,
+    /// `non_empty`.
+    Empty,
+    /// `email`.
+    InvalidEmail,
+    /// `url`.
+    InvalidUrl,
+    /// `ip`, `ipv4` and `ipv6`.
+    InvalidIp,
+    /// `hex` and `hex_color`.
+    InvalidHex,
+    /// `credit_card`.
+    InvalidCreditCard,
+    /// `phone_number` and `phone`.
+    InvalidPhoneNumber,
+    /// `datetime`.
+    InvalidDateTime,
+    /// `uuid` and `uuid_any`.
+    InvalidUuid,
+    /// `no_trailing_whitespace`.
+    TrailingWhitespace,
+    /// `final_newline`.
+    MissingFinalNewline,
+    /// Any error not produced by a built-in rule, such as from `custom`.
+    #[default]
+    Custom,
 }
 
 impl core::fmt::Display for Error {
@@ -144,7 +516,7 @@ impl_path_component_kind!(String => Key);
 impl_path_component_kind!(CompactString => Key);
 impl_path_component_kind!(NoKey => None);
 
-impl<'a, T: PathComponentKind> PathComponentKind for &'a T {
+impl<T: PathComponentKind> PathComponentKind for &T {
     fn component_kind() -> Kind {
         T::component_kind()
     }
@@ -179,6 +551,15 @@ impl Path {
         }
     }
 
+    /// Returns a copy of this path nested under `prefix`.
+    fn prefixed(&self, prefix: &str) -> Self {
+        let mut components = List::new().append((Kind::Key, CompactString::from(prefix)));
+        for (kind, component) in self.__iter().rev() {
+            components = components.append((kind, component.clone()));
+        }
+        Self { components }
+    }
+
     #[doc(hidden)]
     pub fn __iter(
         &self,
@@ -263,13 +644,118 @@ impl<'de> serde::Deserialize<'de> for Path {
         D: serde::Deserializer<'de>,
     {
         let mut components = List::new();
-        for v in SmallVec::<[(Kind, CompactString); 8]>::deserialize(deserializer)? {
+        for v in SmallVec::<(Kind, CompactString), 8>::deserialize(deserializer)? {
             components = components.append(v);
         }
         Ok(Path { components })
     }
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct SerializeEntry<'a> {
+    path: CompactString,
+    kind: ErrorKind,
+    message: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    length: Option<usize>,
+    #[serde(skip_serializing_if = "Severity::is_error")]
+    severity: Severity,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    rule: &'static str,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DeserializeEntry {
+    path: CompactString,
+    #[serde(default)]
+    kind: ErrorKind,
+    message: CompactString,
+    #[serde(default)]
+    length: Option<usize>,
+    #[serde(default)]
+    severity: Severity,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Report {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq as _;
+
+        let mut seq = serializer.serialize_seq(Some(self.errors.len()))?;
+        for (path, error) in self.iter() {
+            seq.serialize_element(&SerializeEntry {
+                path: path.to_compact_string(),
+                kind: error.kind,
+                message: error.message(),
+                code: error.code,
+                length: error.length,
+                severity: error.severity,
+                rule: error.rule,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Report {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let errors = Vec::<DeserializeEntry>::deserialize(deserializer)?
+            .into_iter()
+            .map(|entry| {
+                (
+                    Path::parse_display(&entry.path),
+                    Error {
+                        kind: entry.kind,
+                        message: entry.message,
+                        code: None,
+                        length: entry.length,
+                        severity: entry.severity,
+                        rule: "",
+                    },
+                )
+            })
+            .collect();
+        Ok(Report {
+            errors,
+            first_only: false,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Path {
+    /// Inverse of the `Display` impl, `a.b[0]` is parsed into the keys `a`, `b` and the index `0`.
+    ///
+    /// Keys which contain `.` or `[` and components without a key cannot be recovered.
+    fn parse_display(mut s: &str) -> Self {
+        let mut components = List::new();
+        while !s.is_empty() {
+            if let Some(rest) = s.strip_prefix('[') {
+                let end = rest.find(']').unwrap_or(rest.len());
+                components = components.append((Kind::Index, CompactString::from(&rest[..end])));
+                s = rest.get(end + 1..).unwrap_or("");
+            } else {
+                let rest = s.strip_prefix('.').unwrap_or(s);
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                components = components.append((Kind::Key, CompactString::from(&rest[..end])));
+                s = &rest[end..];
+            }
+        }
+        Path { components }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,6 +793,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn error_display() {
+        let error = crate::rules::length::bytes::apply(&"hello", (1, 4)).unwrap_err();
+        assert_eq!(
+            alloc::format!("{error}"),
+            "length 5 is greater than maximum 4"
+        );
+        assert_eq!(alloc::format!("{error}"), error.message());
+
+        let _: &dyn core::error::Error = &error;
+    }
+
+    #[test]
+    fn error_with_context() {
+        let error = crate::rules::length::bytes::apply(&"hello", (1, 4))
+            .unwrap_err()
+            .with_code("too_long")
+            .with_context("while parsing header");
+        assert_eq!(
+            error.message(),
+            "while parsing header: length 5 is greater than maximum 4"
+        );
+        assert_eq!(error.kind(), ErrorKind::BytesOutOfRange);
+        assert_eq!(error.code(), Some("too_long"));
+        assert_eq!(error.rule(), "bytes");
+        assert_eq!(error.length(), Some(5));
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
@@ -324,5 +838,66 @@ mod tests {
 
             assert_eq!(report.errors, de.errors);
         }
+
+        #[test]
+        fn report_json_shape() {
+            let mut report = Report::new();
+            report.append(
+                Path::new("address").join("zip"),
+                Error::with_kind(
+                    ErrorKind::LengthOutOfRange,
+                    "length 6 is greater than maximum 5",
+                )
+                .with_length(6),
+            );
+            report.append(Path::new("tags").join(1), Error::new("lorem"));
+
+            let json = serde_json::to_string(&report).unwrap();
+            assert_eq!(
+                json,
+                r#"[{"path":"address.zip","kind":"length_out_of_range","message":"length 6 is greater than maximum 5","length":6},{"path":"tags[1]","kind":"custom","message":"lorem"}]"#
+            );
+
+            let de: Report = serde_json::from_str(&json).unwrap();
+            assert_eq!(report.errors, de.errors);
+        }
+
+        #[test]
+        fn report_json_code() {
+            let mut report = Report::new();
+            report.append(
+                Path::new("zip"),
+                Error::new("lorem").with_code("zip_length"),
+            );
+
+            let json = serde_json::to_string(&report).unwrap();
+            assert_eq!(
+                json,
+                r#"[{"path":"zip","kind":"custom","message":"lorem","code":"zip_length"}]"#
+            );
+
+            // codes are `&'static str`, so they are not restored
+            let de: Report = serde_json::from_str(&json).unwrap();
+            assert_eq!(de.errors[0].1.code(), None);
+        }
+
+        #[test]
+        fn report_json_severity() {
+            let mut report = Report::new();
+            report.append(
+                Path::new("name"),
+                Error::new("lorem").with_severity(Severity::Warning),
+            );
+
+            let json = serde_json::to_string(&report).unwrap();
+            assert_eq!(
+                json,
+                r#"[{"path":"name","kind":"custom","message":"lorem","severity":"warning"}]"#
+            );
+
+            let de: Report = serde_json::from_str(&json).unwrap();
+            assert!(de.errors[0].1.is_warning());
+            assert!(de.is_valid());
+        }
     }
 }
@@ -0,0 +1,148 @@
+//! Overridable error messages.
+//!
+//! Every built-in rule builds its message through [`Error::localized`], which first asks the
+//! installed [`MessageProvider`] and falls back to the default English message.
+//!
+//! The length rules pass `len`, `min` and `max`, but `len` is left out when the exact length was
+//! not measured, as `chars` stops counting once a value is over its maximum.
+//!
+//! ```rust
+//! use garde::error::messages::{set_message_provider, Message, MessageProvider};
+//! use garde::external::compact_str::{format_compact, CompactString};
+//!
+//! struct French;
+//!
+//! impl MessageProvider for French {
+//!     fn message(&self, message: &Message<'_>) -> Option<CompactString> {
+//!         match message.id() {
+//!             "length_lower_than" => Some(format_compact!(
+//!                 "la longueur est inférieure à {}",
+//!                 message.param("min")?
+//!             )),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! assert!(set_message_provider(&French).is_ok());
+//! ```
+
+use core::cell::UnsafeCell;
+use core::fmt::{Arguments, Display};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use compact_str::{CompactString, ToCompactString};
+
+use super::{Error, ErrorKind};
+
+/// Produces the text of errors emitted by rules.
+pub trait MessageProvider: Send + Sync {
+    /// Returns the message to use, or `None` to use [`Message::default`].
+    fn message(&self, message: &Message<'_>) -> Option<CompactString>;
+}
+
+/// Everything known about a failed check, used to build its message.
+pub struct Message<'a> {
+    kind: ErrorKind,
+    id: &'static str,
+    params: &'a [(&'static str, &'a dyn Display)],
+    default: Arguments<'a>,
+}
+
+impl<'a> Message<'a> {
+    /// The kind of the error being built.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Identifies the exact message within its [`ErrorKind`], such as `length_lower_than`.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Look up a parameter of the message by name, such as `min`.
+    pub fn param(&self, name: &str) -> Option<&'a dyn Display> {
+        self.params
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// All parameters of the message.
+    pub fn params(&self) -> &'a [(&'static str, &'a dyn Display)] {
+        self.params
+    }
+
+    /// The default English message.
+    pub fn default(&self) -> &Arguments<'a> {
+        &self.default
+    }
+}
+
+const UNSET: u8 = 0;
+const WRITING: u8 = 1;
+const SET: u8 = 2;
+
+struct ProviderCell {
+    state: AtomicU8,
+    provider: UnsafeCell<Option<&'static dyn MessageProvider>>,
+}
+
+// SAFETY: `provider` is written once, by the thread which moved `state` from `UNSET` to `WRITING`,
+// and only read after that thread has published it by storing `SET`.
+unsafe impl Sync for ProviderCell {}
+
+static PROVIDER: ProviderCell = ProviderCell {
+    state: AtomicU8::new(UNSET),
+    provider: UnsafeCell::new(None),
+};
+
+/// Install a [`MessageProvider`] used by all rules from now on.
+///
+/// A provider can only be installed once. Any later call leaves the installed provider
+/// in place and returns the rejected one as `Err`.
+pub fn set_message_provider(
+    provider: &'static dyn MessageProvider,
+) -> Result<(), &'static dyn MessageProvider> {
+    if PROVIDER
+        .state
+        .compare_exchange(UNSET, WRITING, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        return Err(provider);
+    }
+    // SAFETY: Winning the exchange above makes this the only write, and nobody reads until `SET`.
+    unsafe { *PROVIDER.provider.get() = Some(provider) };
+    PROVIDER.state.store(SET, Ordering::Release);
+    Ok(())
+}
+
+fn provider() -> Option<&'static dyn MessageProvider> {
+    if PROVIDER.state.load(Ordering::Acquire) != SET {
+        return None;
+    }
+    // SAFETY: `SET` is only stored after the single write has completed.
+    unsafe { *PROVIDER.provider.get() }
+}
+
+impl Error {
+    /// Create an [`Error`] whose message is produced by the installed [`MessageProvider`],
+    /// or `default` if there is none or it does not provide one.
+    pub fn localized(
+        kind: ErrorKind,
+        id: &'static str,
+        params: &[(&'static str, &dyn Display)],
+        default: Arguments<'_>,
+    ) -> Self {
+        let message = Message {
+            kind,
+            id,
+            params,
+            default,
+        };
+        let text = provider()
+            .and_then(|provider| provider.message(&message))
+            .unwrap_or_else(|| message.default.to_compact_string());
+        Self::with_kind(kind, text)
+    }
+}
@@ -1,16 +1,19 @@
 #![doc = include_str!("../README.md")]
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+pub mod bounded;
 pub mod error;
 pub mod rules;
 pub mod validate;
 
-pub use error::{Error, Path, Report};
+pub use error::{Error, ErrorKind, Path, Report, Severity};
 #[cfg(feature = "derive")]
 pub use garde_derive::{select, Validate};
-pub use validate::{Unvalidated, Valid, Validate};
+pub use validate::{validate_and_collect, validate_iter, Unvalidated, Valid, Validate};
 
 pub type Result = ::core::result::Result<(), Error>;
 
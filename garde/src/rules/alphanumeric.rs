@@ -13,30 +13,50 @@
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
-    if !v.validate_alphanumeric() {
-        return Err(Error::new("not alphanumeric"));
+    if let Err(NotAlphanumeric { index, character }) = v.validate_alphanumeric() {
+        return Err(Error::localized(
+            ErrorKind::NotAlphanumeric,
+            "alphanumeric",
+            &[("character", &character), ("index", &index)],
+            format_args!("not alphanumeric, found {character:?} at index {index}"),
+        )
+        .with_rule("alphanumeric"));
     }
     Ok(())
 }
 
 pub trait Alphanumeric {
-    fn validate_alphanumeric(&self) -> bool;
+    fn validate_alphanumeric(&self) -> Result<(), NotAlphanumeric>;
+}
+
+/// The first offending character, and its index in terms of `char`s.
+pub struct NotAlphanumeric {
+    pub index: usize,
+    pub character: char,
 }
 
 impl<T: AsStr> Alphanumeric for T {
-    fn validate_alphanumeric(&self) -> bool {
-        self.as_str().chars().all(|c| c.is_alphanumeric())
+    fn validate_alphanumeric(&self) -> Result<(), NotAlphanumeric> {
+        match self
+            .as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_alphanumeric())
+        {
+            Some((index, character)) => Err(NotAlphanumeric { index, character }),
+            None => Ok(()),
+        }
     }
 }
 
 impl<T: Alphanumeric> Alphanumeric for Option<T> {
-    fn validate_alphanumeric(&self) -> bool {
+    fn validate_alphanumeric(&self) -> Result<(), NotAlphanumeric> {
         match self {
             Some(value) => value.validate_alphanumeric(),
-            None => true,
+            None => Ok(()),
         }
     }
 }
@@ -13,30 +13,50 @@
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Ascii>(v: &T, _: ()) -> Result<(), Error> {
-    if !v.validate_ascii() {
-        return Err(Error::new("not ascii"));
+    if let Err(NotAscii { index, character }) = v.validate_ascii() {
+        return Err(Error::localized(
+            ErrorKind::NotAscii,
+            "ascii",
+            &[("character", &character), ("index", &index)],
+            format_args!("not ascii, found {character:?} at index {index}"),
+        )
+        .with_rule("ascii"));
     }
     Ok(())
 }
 
 pub trait Ascii {
-    fn validate_ascii(&self) -> bool;
+    fn validate_ascii(&self) -> Result<(), NotAscii>;
+}
+
+/// The first offending character, and its index in terms of `char`s.
+pub struct NotAscii {
+    pub index: usize,
+    pub character: char,
 }
 
 impl<T: AsStr> Ascii for T {
-    fn validate_ascii(&self) -> bool {
-        self.as_str().is_ascii()
+    fn validate_ascii(&self) -> Result<(), NotAscii> {
+        match self
+            .as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii())
+        {
+            Some((index, character)) => Err(NotAscii { index, character }),
+            None => Ok(()),
+        }
     }
 }
 
 impl<T: Ascii> Ascii for Option<T> {
-    fn validate_ascii(&self) -> bool {
+    fn validate_ascii(&self) -> Result<(), NotAscii> {
         match self {
             Some(value) => value.validate_ascii(),
-            None => true,
+            None => Ok(()),
         }
     }
 }
@@ -0,0 +1,65 @@
+//! ASCII alphanumeric validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ascii_alphanumeric)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Unlike [`alphanumeric`][super::alphanumeric], this only accepts `[A-Za-z0-9]`,
+//! rejecting non-ASCII letters and digits such as `ß` or `١`.
+//!
+//! The entrypoint is the [`AsciiAlphanumeric`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ascii_alphanumeric)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: AsciiAlphanumeric>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(NotAsciiAlphanumeric { index, character }) = v.validate_ascii_alphanumeric() {
+        return Err(Error::localized(
+            ErrorKind::NotAsciiAlphanumeric,
+            "ascii_alphanumeric",
+            &[("character", &character), ("index", &index)],
+            format_args!("not ascii alphanumeric, found {character:?} at index {index}"),
+        )
+        .with_rule("ascii_alphanumeric"));
+    }
+    Ok(())
+}
+
+pub trait AsciiAlphanumeric {
+    fn validate_ascii_alphanumeric(&self) -> Result<(), NotAsciiAlphanumeric>;
+}
+
+/// The first offending character, and its index in terms of `char`s.
+pub struct NotAsciiAlphanumeric {
+    pub index: usize,
+    pub character: char,
+}
+
+impl<T: AsStr> AsciiAlphanumeric for T {
+    fn validate_ascii_alphanumeric(&self) -> Result<(), NotAsciiAlphanumeric> {
+        match self
+            .as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_alphanumeric())
+        {
+            Some((index, character)) => Err(NotAsciiAlphanumeric { index, character }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: AsciiAlphanumeric> AsciiAlphanumeric for Option<T> {
+    fn validate_ascii_alphanumeric(&self) -> Result<(), NotAsciiAlphanumeric> {
+        match self {
+            Some(value) => value.validate_ascii_alphanumeric(),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Bit width validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(bits(max = 12))]
+//!     v: u16,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Bits`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(bits(...))]` rule.
+//!
+//! This trait is implemented for all primitive unsigned integer types.
+//! A value fits in `max` bits if `value >> max == 0`, so `4095` fits in 12 bits, but `4096` does not.
+//! A `max` of at least the width of the type accepts every value.
+
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Bits>(v: &T, (max,): (u32,)) -> Result<(), Error> {
+    if !v.validate_bits(max) {
+        return Err(Error::localized(
+            ErrorKind::TooManyBits,
+            "bits",
+            &[("max", &max)],
+            format_args!("does not fit in {max} bits"),
+        )
+        .with_rule("bits"));
+    }
+    Ok(())
+}
+
+pub trait Bits {
+    /// Returns `true` if the value fits in `max_bits` bits.
+    fn validate_bits(&self, max_bits: u32) -> bool;
+}
+
+macro_rules! impl_for_uint {
+    ($($T:ident),*) => {
+        $(
+            impl Bits for $T {
+                fn validate_bits(&self, max_bits: u32) -> bool {
+                    // `checked_shr` fails when shifting by at least the width of the type,
+                    // in which case every value fits.
+                    self.checked_shr(max_bits).unwrap_or(0) == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_for_uint!(u8, u16, u32, u64, u128, usize);
+
+impl<T: Bits> Bits for Option<T> {
+    fn validate_bits(&self, max_bits: u32) -> bool {
+        match self {
+            Some(value) => value.validate_bits(max_bits),
+            None => true,
+        }
+    }
+}
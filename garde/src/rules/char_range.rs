@@ -0,0 +1,67 @@
+//! Character range validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(char_range(min='A', max='F'))]
+//!     grade: char,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`CharRange`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(char_range(...))]` rule.
+//!
+//! This trait is implemented for `char`, comparing by code point. Omitted bounds default to `char::MIN` and `char::MAX`.
+
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: CharRange>(v: &T, (min, max): (Option<char>, Option<char>)) -> Result<(), Error> {
+    let min = min.unwrap_or(char::MIN);
+    let max = max.unwrap_or(char::MAX);
+    match v.validate_char_range(min, max) {
+        Ok(()) => Ok(()),
+        Err(OutOfCharRange::Lower) => Err(Error::localized(
+            ErrorKind::CharOutOfRange,
+            "char_range_lower_than",
+            &[("min", &min)],
+            format_args!("lower than {min:?}"),
+        )
+        .with_rule("char_range")),
+        Err(OutOfCharRange::Upper) => Err(Error::localized(
+            ErrorKind::CharOutOfRange,
+            "char_range_greater_than",
+            &[("max", &max)],
+            format_args!("greater than {max:?}"),
+        )
+        .with_rule("char_range")),
+    }
+}
+
+pub trait CharRange {
+    fn validate_char_range(&self, min: char, max: char) -> Result<(), OutOfCharRange>;
+}
+
+pub enum OutOfCharRange {
+    Lower,
+    Upper,
+}
+
+impl CharRange for char {
+    fn validate_char_range(&self, min: char, max: char) -> Result<(), OutOfCharRange> {
+        if *self < min {
+            Err(OutOfCharRange::Lower)
+        } else if *self > max {
+            Err(OutOfCharRange::Upper)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: CharRange> CharRange for Option<T> {
+    fn validate_char_range(&self, min: char, max: char) -> Result<(), OutOfCharRange> {
+        match self {
+            Some(value) => value.validate_char_range(min, max),
+            None => Ok(()),
+        }
+    }
+}
@@ -16,13 +16,18 @@
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
-use alloc::format;
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_contains(pat) {
-        return Err(Error::new(format!("does not contain \"{pat}\"")));
+        return Err(Error::localized(
+            ErrorKind::MissingSubstring,
+            "contains",
+            &[("pattern", &pat)],
+            format_args!("does not contain \"{pat}\""),
+        )
+        .with_rule("contains"));
     }
     Ok(())
 }
@@ -1,4 +1,4 @@
-//! Credit card validation using the [`card_validate`] crate.
+//! Credit card validation.
 //!
 //! ```rust
 //! #[derive(garde::Validate)]
@@ -11,16 +11,24 @@
 //! The entrypoint is the [`CreditCard`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(credit_card)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! Spaces and hyphens are ignored. The remaining characters must be 12 to 19 ASCII digits
+//! which pass the [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) checksum.
 
-use alloc::format;
 use core::fmt::Display;
 
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: CreditCard>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(e) = v.validate_credit_card() {
-        return Err(Error::new(format!("not a valid credit card number: {e}")));
+        return Err(Error::localized(
+            ErrorKind::InvalidCreditCard,
+            "credit_card",
+            &[("error", &e)],
+            format_args!("not a valid credit card number: {e}"),
+        )
+        .with_rule("credit_card"));
     }
     Ok(())
 }
@@ -35,7 +43,32 @@ impl<T: AsStr> CreditCard for T {
     type Error = InvalidCard;
 
     fn validate_credit_card(&self) -> Result<(), Self::Error> {
-        let _ = card_validate::Validate::from(self.as_str())?;
+        let mut len = 0usize;
+        let mut sum = 0u32;
+        for c in self.as_str().chars().rev() {
+            if c == ' ' || c == '-' {
+                continue;
+            }
+            let Some(digit) = c.to_digit(10) else {
+                return Err(InvalidCard::InvalidFormat);
+            };
+            // every second digit from the right is doubled.
+            // only the last digit of the sum matters, and keeping it below 10 means it can't overflow on long inputs
+            let digit = match len % 2 {
+                0 => digit,
+                _ if digit * 2 > 9 => digit * 2 - 9,
+                _ => digit * 2,
+            };
+            sum = (sum + digit) % 10;
+            len += 1;
+        }
+
+        if !(12..=19).contains(&len) {
+            return Err(InvalidCard::InvalidLength);
+        }
+        if sum != 0 {
+            return Err(InvalidCard::InvalidLuhn);
+        }
         Ok(())
     }
 }
@@ -51,21 +84,18 @@ impl<T: CreditCard> CreditCard for Option<T> {
     }
 }
 
-pub struct InvalidCard(card_validate::ValidateError);
+pub enum InvalidCard {
+    InvalidFormat,
+    InvalidLength,
+    InvalidLuhn,
+}
+
 impl Display for InvalidCard {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
-            card_validate::ValidateError::InvalidFormat => write!(f, "invalid format"),
-            card_validate::ValidateError::InvalidLength => write!(f, "invalid length"),
-            card_validate::ValidateError::InvalidLuhn => write!(f, "invalid luhn"),
-            card_validate::ValidateError::UnknownType => write!(f, "unknown type"),
-            _ => write!(f, "unknown error"),
+        match self {
+            InvalidCard::InvalidFormat => write!(f, "invalid format"),
+            InvalidCard::InvalidLength => write!(f, "invalid length"),
+            InvalidCard::InvalidLuhn => write!(f, "invalid luhn"),
         }
     }
 }
-
-impl From<card_validate::ValidateError> for InvalidCard {
-    fn from(value: card_validate::ValidateError) -> Self {
-        Self(value)
-    }
-}
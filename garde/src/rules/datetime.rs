@@ -0,0 +1,122 @@
+//! Structural [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) date and time validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(datetime)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A valid value is a `full-date` (`YYYY-MM-DD`), optionally followed by `T` (or `t`, or a space)
+//! and a `partial-time` (`HH:MM:SS`, with optional fractional seconds), optionally followed by
+//! an offset (`Z`, `z`, or `+HH:MM`/`-HH:MM`).
+//!
+//! Only the format and the ranges of each component are checked. Whether the day exists in
+//! the given month, or whether a leap second actually occurred, is not.
+//!
+//! The entrypoint is the [`DateTime`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(datetime)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: DateTime>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_datetime() {
+        return Err(Error::localized(
+            ErrorKind::InvalidDateTime,
+            "datetime",
+            &[],
+            format_args!("not a valid RFC 3339 date-time"),
+        )
+        .with_rule("datetime"));
+    }
+    Ok(())
+}
+
+pub trait DateTime {
+    fn validate_datetime(&self) -> bool;
+}
+
+impl<T: AsStr> DateTime for T {
+    fn validate_datetime(&self) -> bool {
+        is_rfc3339(self.as_str().as_bytes())
+    }
+}
+
+impl<T: DateTime> DateTime for Option<T> {
+    fn validate_datetime(&self) -> bool {
+        match self {
+            Some(value) => value.validate_datetime(),
+            None => true,
+        }
+    }
+}
+
+fn is_rfc3339(v: &[u8]) -> bool {
+    let Some(rest) = date(v) else {
+        return false;
+    };
+    let rest = match rest {
+        [] => return true,
+        [b'T' | b't' | b' ', rest @ ..] => rest,
+        _ => return false,
+    };
+    let Some(rest) = time(rest) else {
+        return false;
+    };
+    match rest {
+        [] | [b'Z' | b'z'] => true,
+        [b'+' | b'-', rest @ ..] => matches!(hour_minute(rest), Some([])),
+        _ => false,
+    }
+}
+
+/// `YYYY-MM-DD`
+fn date(v: &[u8]) -> Option<&[u8]> {
+    let (_, rest) = number(v, 4, 9999)?;
+    let rest = rest.strip_prefix(b"-")?;
+    let (month, rest) = number(rest, 2, 12)?;
+    let rest = rest.strip_prefix(b"-")?;
+    let (day, rest) = number(rest, 2, 31)?;
+    (month >= 1 && day >= 1).then_some(rest)
+}
+
+/// `HH:MM:SS`, followed by optional fractional seconds.
+fn time(v: &[u8]) -> Option<&[u8]> {
+    let rest = hour_minute(v)?;
+    let rest = rest.strip_prefix(b":")?;
+    let (_, rest) = number(rest, 2, 60)?;
+    match rest.strip_prefix(b".") {
+        Some(fraction) => {
+            let digits = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+            (digits > 0).then(|| &fraction[digits..])
+        }
+        None => Some(rest),
+    }
+}
+
+/// `HH:MM`
+fn hour_minute(v: &[u8]) -> Option<&[u8]> {
+    let (_, rest) = number(v, 2, 23)?;
+    let rest = rest.strip_prefix(b":")?;
+    let (_, rest) = number(rest, 2, 59)?;
+    Some(rest)
+}
+
+/// Parses exactly `digits` ASCII digits with a value of at most `max`.
+fn number(v: &[u8], digits: usize, max: u32) -> Option<(u32, &[u8])> {
+    if v.len() < digits {
+        return None;
+    }
+    let (head, rest) = v.split_at(digits);
+    let mut value = 0;
+    for c in head {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + u32::from(c - b'0');
+    }
+    (value <= max).then_some((value, rest))
+}
@@ -11,30 +11,25 @@
 //! The entrypoint is the [`Email`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(email)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! The validation follows the [HTML5](https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address) definition,
+//! implemented by hand so that it does not depend on `regex`. The value is split on the last `@`.
 
-use alloc::format;
 use core::fmt::Display;
 use core::str::FromStr;
 
-use super::pattern::Matcher;
 use super::AsStr;
-use crate::error::Error;
-
-macro_rules! init_regex {
-    ($var:ident => $p:literal) => {
-        #[cfg(not(all(feature = "js-sys", target_arch = "wasm32", target_os = "unknown")))]
-        static $var: $crate::rules::pattern::regex::StaticPattern =
-            $crate::rules::pattern::regex::init_pattern!($p);
-
-        #[cfg(all(feature = "js-sys", target_arch = "wasm32", target_os = "unknown"))]
-        static $var: $crate::rules::pattern::regex_js_sys::StaticPattern =
-            $crate::rules::pattern::regex_js_sys::init_pattern!($p);
-    };
-}
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Email>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(e) = v.validate_email() {
-        return Err(Error::new(format!("not a valid email: {e}")));
+        return Err(Error::localized(
+            ErrorKind::InvalidEmail,
+            "email",
+            &[("error", &e)],
+            format_args!("not a valid email: {e}"),
+        )
+        .with_rule("email"));
     }
     Ok(())
 }
@@ -96,17 +91,13 @@ pub fn parse_email(s: &str) -> Result<(), InvalidEmail> {
         return Err(InvalidEmail::Empty);
     }
 
-    let (user, domain) = s.split_once('@').ok_or(InvalidEmail::MissingAt)?;
+    let (user, domain) = s.rsplit_once('@').ok_or(InvalidEmail::MissingAt)?;
 
     if user.len() > 64 {
         return Err(InvalidEmail::UserLengthExceeded);
     }
 
-    init_regex! {
-        USER_RE => r"(?i-u)^[a-z0-9.!#$%&'*+/=?^_`{|}~-]+\z"
-    }
-
-    if !USER_RE.is_match(user) {
+    if !is_valid_user(user) {
         return Err(InvalidEmail::InvalidUser);
     }
 
@@ -136,20 +127,31 @@ pub fn parse_email(s: &str) -> Result<(), InvalidEmail> {
     Ok(())
 }
 
-fn is_valid_domain(domain: &str) -> bool {
-    init_regex! {
-        DOMAIN_NAME_RE => r"(?i-u)^[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?(?:\.[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?)*$"
-    };
+fn is_valid_user(user: &str) -> bool {
+    !user.is_empty()
+        && user
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b".!#$%&'*+/=?^_`{|}~-".contains(&b))
+}
 
-    if DOMAIN_NAME_RE.is_match(domain) {
-        return true;
-    }
+fn is_valid_domain(domain: &str) -> bool {
+    domain.split('.').all(is_valid_label) || is_smtp_addr(domain)
+}
 
-    if is_smtp_addr(domain) {
-        return true;
+/// A label is 1 to 63 ASCII alphanumerics or hyphens, and may not begin or end with a hyphen.
+fn is_valid_label(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    match (bytes.first(), bytes.last()) {
+        (Some(first), Some(last)) => {
+            bytes.len() <= 63
+                && first.is_ascii_alphanumeric()
+                && last.is_ascii_alphanumeric()
+                && bytes
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+        }
+        _ => false,
     }
-
-    false
 }
 
 fn is_smtp_addr(domain: &str) -> bool {
@@ -215,9 +217,13 @@ mod tests {
             ("abc@bar", None),
             ("a @x.cz", Some(InvalidEmail::InvalidUser)),
             ("abc@.com", Some(InvalidEmail::InvalidDomain)),
+            ("something@@somewhere.com", Some(InvalidEmail::InvalidUser)),
+            ("a@b.co", None),
+            ("@b.co", Some(InvalidEmail::InvalidUser)),
+            ("a@", Some(InvalidEmail::InvalidDomain)),
             (
-                "something@@somewhere.com",
-                Some(InvalidEmail::InvalidDomain),
+                "first.last+tag@mail.subdomain.of.a.very-long-company-name.example.com",
+                None,
             ),
             ("email@127.0.0.1", None),
             ("email@[127.0.0.256]", Some(InvalidEmail::InvalidDomain)),
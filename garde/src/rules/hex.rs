@@ -0,0 +1,91 @@
+//! Hexadecimal string validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(hex)]
+//!     key: String,
+//!     #[garde(hex_color)]
+//!     color: String,
+//! }
+//! ```
+//!
+//! - `hex` accepts an even number of `[0-9a-fA-F]` digits, i.e. a hex-encoded byte string.
+//! - `hex_color` accepts an optional leading `#`, followed by 3, 6 or 8 hex digits (`#RGB`, `#RRGGBB`, `#RRGGBBAA`).
+//!
+//! The entrypoint is the [`Hex`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(hex)]` and `#[garde(hex_color)]` rules.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use core::fmt::Display;
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Hex>(v: &T, (kind,): (HexKind,)) -> Result<(), Error> {
+    if !v.validate_hex(kind) {
+        return Err(Error::localized(
+            ErrorKind::InvalidHex,
+            "hex",
+            &[("kind", &kind)],
+            format_args!("not a valid {kind}"),
+        )
+        .with_rule(kind.rule()));
+    }
+    Ok(())
+}
+
+pub trait Hex {
+    fn validate_hex(&self, kind: HexKind) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum HexKind {
+    String,
+    Color,
+}
+
+impl HexKind {
+    /// The name of the rule which checks for this kind of value.
+    fn rule(self) -> &'static str {
+        match self {
+            HexKind::String => "hex",
+            HexKind::Color => "hex_color",
+        }
+    }
+}
+
+impl Display for HexKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HexKind::String => write!(f, "hex string"),
+            HexKind::Color => write!(f, "hex color"),
+        }
+    }
+}
+
+fn is_hex_digits(v: &str) -> bool {
+    v.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+impl<T: AsStr> Hex for T {
+    fn validate_hex(&self, kind: HexKind) -> bool {
+        let v = self.as_str();
+        match kind {
+            HexKind::String => v.len().is_multiple_of(2) && is_hex_digits(v),
+            HexKind::Color => {
+                let digits = v.strip_prefix('#').unwrap_or(v);
+                matches!(digits.len(), 3 | 6 | 8) && is_hex_digits(digits)
+            }
+        }
+    }
+}
+
+impl<T: Hex> Hex for Option<T> {
+    fn validate_hex(&self, kind: HexKind) -> bool {
+        match self {
+            Some(value) => value.validate_hex(kind),
+            None => true,
+        }
+    }
+}
@@ -8,10 +8,13 @@
 //! }
 //! ```
 //!
+//! On maps, `inner` applies to the values, and errors are reported at `<field>.<key>`.
+//! It may also be spelled `inner_values`, alongside `inner_keys` which is the same as [`keys`][super::keys].
+//!
 //! The entrypoint is the [`Inner`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(inner(..))]` rule.
 
-use alloc::vec::Vec;
 use crate::error::{NoKey, PathComponentKind};
+use alloc::vec::Vec;
 
 pub fn apply<T, U, K, F>(field: &T, f: F)
 where
@@ -51,7 +54,7 @@ impl<const N: usize, T> Inner<T> for [T; N] {
     }
 }
 
-impl<'a, T> Inner<T> for &'a [T] {
+impl<T> Inner<T> for &[T] {
     type Key = usize;
 
     fn validate_inner<F>(&self, mut f: F)
@@ -64,6 +67,33 @@ impl<'a, T> Inner<T> for &'a [T] {
     }
 }
 
+impl<K: PathComponentKind, V> Inner<V> for alloc::collections::BTreeMap<K, V> {
+    type Key = K;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&V, &Self::Key),
+    {
+        for (key, value) in self.iter() {
+            f(value, key);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: PathComponentKind, V, S> Inner<V> for std::collections::HashMap<K, V, S> {
+    type Key = K;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&V, &Self::Key),
+    {
+        for (key, value) in self.iter() {
+            f(value, key);
+        }
+    }
+}
+
 impl<T> Inner<T> for Option<T> {
     type Key = NoKey;
 
@@ -12,15 +12,20 @@
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
-use alloc::format;
 use core::fmt::Display;
 
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Ip>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
     if v.validate_ip(kind).is_err() {
-        return Err(Error::new(format!("not a valid {kind} address")));
+        return Err(Error::localized(
+            ErrorKind::InvalidIp,
+            "ip",
+            &[("kind", &kind)],
+            format_args!("not a valid {kind} address"),
+        )
+        .with_rule(kind.rule()));
     }
     Ok(())
 }
@@ -38,6 +43,17 @@ pub enum IpKind {
     V6,
 }
 
+impl IpKind {
+    /// The name of the rule which checks for this kind of address.
+    fn rule(self) -> &'static str {
+        match self {
+            IpKind::Any => "ip",
+            IpKind::V4 => "ipv4",
+            IpKind::V6 => "ipv6",
+        }
+    }
+}
+
 impl Display for IpKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
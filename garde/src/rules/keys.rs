@@ -0,0 +1,76 @@
+//! Map key validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(keys(length(max=16)))]
+//!     v: std::collections::BTreeMap<String, u32>,
+//! }
+//! ```
+//!
+//! Errors for a key are reported at `<field>.<key>.key`, while errors produced by diving into
+//! the value are reported at `<field>.<key>.<value field>`. The two only collide when the value
+//! has a field named `key`.
+//!
+//! The modifier may also be spelled `inner_keys`, see [`inner`][super::inner] for validating the values.
+//!
+//! The entrypoint is the [`Keys`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(keys(..))]` rule.
+
+use crate::error::PathComponentKind;
+
+pub fn apply<T, U, K, F>(field: &T, f: F)
+where
+    T: Keys<U, Key = K>,
+    F: FnMut(&U, &K),
+{
+    field.validate_keys(f)
+}
+
+pub trait Keys<T> {
+    type Key: PathComponentKind;
+
+    /// Calls `f` with each key, along with the path component for its entry.
+    fn validate_keys<F>(&self, f: F)
+    where
+        F: FnMut(&T, &Self::Key);
+}
+
+impl<K: PathComponentKind, V> Keys<K> for alloc::collections::BTreeMap<K, V> {
+    type Key = K;
+
+    fn validate_keys<F>(&self, mut f: F)
+    where
+        F: FnMut(&K, &Self::Key),
+    {
+        for key in self.keys() {
+            f(key, key);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: PathComponentKind, V, S> Keys<K> for std::collections::HashMap<K, V, S> {
+    type Key = K;
+
+    fn validate_keys<F>(&self, mut f: F)
+    where
+        F: FnMut(&K, &Self::Key),
+    {
+        for key in self.keys() {
+            f(key, key);
+        }
+    }
+}
+
+impl<T, U: Keys<T>> Keys<T> for Option<U> {
+    type Key = U::Key;
+
+    fn validate_keys<F>(&self, f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        if let Some(map) = self {
+            map.validate_keys(f)
+        }
+    }
+}
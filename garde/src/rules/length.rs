@@ -3,7 +3,7 @@
 //! ```rust
 //! #[derive(garde::Validate)]
 //! struct Test {
-//!     #[garde(length(min=1, max=100))]
+//!     #[garde(length(chars, min=1, max=100))]
 //!     v: String,
 //! }
 //! ```
@@ -14,6 +14,9 @@
 //! - [`Chars`][chars::Chars]
 //! - [`Graphemes`][graphemes::Graphemes]
 //! - [`Utf16CodeUnits`][utf16::Utf16CodeUnits]
+//! - [`Words`][words::Words]
+//! - [`Lines`][lines::Lines]
+//! - `Base64Decoded`, with the `base64` feature
 //!
 //! The mode is configured on the `length` rule:
 //! ```rust
@@ -27,6 +30,14 @@
 //! }
 //! ```
 //!
+//! Adding `trimmed` measures string-like values after trimming surrounding whitespace,
+//! see [`trimmed`] for details.
+//!
+//! `split = "<delimiter>"` counts the segments of a string-like value instead, see [`split`] for details.
+//!
+//! Custom validators which need to choose the mode at runtime can use [`apply_with_mode`] with a [`LengthMode`],
+//! or [`apply_all`] to run several checks and collect every failure.
+//!
 //! Here's what implementing the trait for a custom string-like type might look like:
 //! ```rust
 //! #[repr(transparent)]
@@ -42,33 +53,245 @@
 //! See each trait for more information.
 //!
 
+#[cfg(feature = "base64")]
+pub mod base64_decoded;
+
 pub mod bytes;
 
-use alloc::format;
 pub use bytes::HasBytes;
 
 pub mod chars;
 pub use chars::HasChars;
 
+pub mod count;
+pub use count::{Countable, HasCount};
+
 #[cfg(feature = "unicode")]
 pub mod graphemes;
 #[cfg(feature = "unicode")]
 pub use graphemes::HasGraphemes;
 
+pub mod lines;
+pub use lines::HasLines;
+
 pub mod simple;
 pub use simple::HasSimpleLength;
 
+pub mod split;
+
+pub mod trimmed;
+
 pub mod utf16;
 pub use utf16::HasUtf16CodeUnits;
 
-use crate::error::Error;
+pub mod words;
+pub use words::HasWords;
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::error::{Error, ErrorKind};
+
+/// Inclusive `min..=max` bounds accepted by every length mode's `apply`.
+///
+/// Tuples of `(min, max)` and `min..=max` ranges convert into [`Bounds`], so existing call sites keep working:
+/// ```rust
+/// use garde::rules::length::{simple, Bounds};
+///
+/// assert!(simple::apply(&"hello", Bounds::new(1, 10)).is_ok());
+/// assert!(simple::apply(&"hello", (1, 10)).is_ok());
+/// assert!(simple::apply(&"hello", 1..=10).is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Bounds {
+    /// Create new bounds.
+    ///
+    /// In debug builds, this panics if `min` is greater than `max`.
+    pub fn new(min: usize, max: usize) -> Self {
+        debug_assert!(min <= max, "`min` must be lower than or equal to `max`");
+        Self { min, max }
+    }
+}
+
+impl From<(usize, usize)> for Bounds {
+    fn from((min, max): (usize, usize)) -> Self {
+        Self::new(min, max)
+    }
+}
+
+impl From<RangeInclusive<usize>> for Bounds {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        Self::new(*range.start(), *range.end())
+    }
+}
+
+impl From<Bounds> for (usize, usize) {
+    fn from(Bounds { min, max }: Bounds) -> Self {
+        (min, max)
+    }
+}
+
+/// A length mode which can be selected at runtime, see [`apply_with_mode`].
+///
+/// Some modes only exist with their feature enabled, so this enum is non-exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthMode {
+    /// Count bytes, requires [`Bytes`][bytes::Bytes].
+    Bytes,
+    /// Count Unicode Scalar Values, requires [`Chars`][chars::Chars].
+    Chars,
+    /// Count UTF-16 code units, requires [`Utf16CodeUnits`][utf16::Utf16CodeUnits].
+    Utf16,
+    /// Count grapheme clusters, requires [`Graphemes`][graphemes::Graphemes].
+    #[cfg(feature = "unicode")]
+    Graphemes,
+}
+
+macro_rules! any_length_mode {
+    ($($bound:path),+) => {
+        /// Implemented by types which support every [`LengthMode`].
+        ///
+        /// This is blanket-implemented for any type implementing the trait required by each mode.
+        pub trait AnyLengthMode: $($bound +)+ {}
 
-fn check_len(len: usize, min: usize, max: usize) -> Result<(), Error> {
-    if len < min {
-        Err(Error::new(format!("length is lower than {min}")))
+        impl<T: $($bound +)+> AnyLengthMode for T {}
+    };
+}
+
+#[cfg(not(feature = "unicode"))]
+any_length_mode!(bytes::Bytes, chars::Chars, utf16::Utf16CodeUnits);
+#[cfg(feature = "unicode")]
+any_length_mode!(
+    bytes::Bytes,
+    chars::Chars,
+    utf16::Utf16CodeUnits,
+    graphemes::Graphemes
+);
+
+/// Validate the length of `v` counted according to `mode`.
+///
+/// ```rust
+/// use garde::rules::length::{apply_with_mode, LengthMode};
+///
+/// assert!(apply_with_mode(&"😂", (1, 1), LengthMode::Chars).is_ok());
+/// assert!(apply_with_mode(&"😂", (1, 1), LengthMode::Bytes).is_err());
+/// ```
+pub fn apply_with_mode<T: AnyLengthMode>(
+    v: &T,
+    bounds: impl Into<Bounds>,
+    mode: LengthMode,
+) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    match mode {
+        LengthMode::Bytes => v.validate_num_bytes(min, max),
+        LengthMode::Chars => v.validate_num_chars(min, max),
+        LengthMode::Utf16 => v.validate_num_code_units(min, max),
+        #[cfg(feature = "unicode")]
+        LengthMode::Graphemes => v.validate_num_graphemes(min, max),
+    }
+}
+
+/// Run every `(mode, bounds)` check against `v`, collecting the errors of all failing checks.
+///
+/// The returned errors are in the same order as `checks`, and the list is empty if every check passed.
+///
+/// ```rust
+/// use garde::rules::length::{apply_all, Bounds, LengthMode};
+///
+/// let checks = [
+///     (LengthMode::Bytes, Bounds::new(0, 8)),
+///     (LengthMode::Chars, Bounds::new(0, 4)),
+/// ];
+/// assert!(apply_all(&"abcd", &checks).is_empty());
+/// assert_eq!(apply_all(&"abcdé", &checks).len(), 1);
+/// ```
+pub fn apply_all<T: AnyLengthMode>(v: &T, checks: &[(LengthMode, Bounds)]) -> Vec<Error> {
+    checks
+        .iter()
+        .filter_map(|&(mode, bounds)| apply_with_mode(v, bounds, mode).err())
+        .collect()
+}
+
+/// Checks that `len` lies within `min..=max`, producing the same errors as the built-in length modes.
+///
+/// Both bounds are inclusive. When `min == max`, any other length is reported as not being exactly `min`.
+/// The error has the kind [`ErrorKind::LengthOutOfRange`] and the rule name `"length"`,
+/// and carries `len` as its [`Error::length`].
+///
+/// This is meant for custom rules which measure a length of their own:
+///
+/// ```rust
+/// use garde::rules::length::check_len;
+///
+/// assert!(check_len(3, 1, 5).is_ok());
+///
+/// let error = check_len(6, 1, 5).unwrap_err();
+/// assert_eq!(error.message(), "length 6 is greater than maximum 5");
+/// ```
+pub fn check_len(len: usize, min: usize, max: usize) -> Result<(), Error> {
+    check_kind_len("length", ErrorKind::LengthOutOfRange, len, min, max)
+}
+
+/// The error for a value which is known to exceed `max`, without having measured its exact length.
+///
+/// This uses the same ids and messages as [`check_kind_len`], minus the `len` parameter.
+fn greater_than_max(rule: &'static str, kind: ErrorKind, min: usize, max: usize) -> Error {
+    let params: &[(&'static str, &dyn core::fmt::Display)] = &[("min", &min), ("max", &max)];
+    let error = if min == max {
+        Error::localized(
+            kind,
+            "length_exact",
+            params,
+            format_args!("length is not exactly {min}"),
+        )
+    } else {
+        Error::localized(
+            kind,
+            "length_greater_than",
+            params,
+            format_args!("length is greater than maximum {max}"),
+        )
+    };
+    error.with_rule(rule)
+}
+
+/// `rule` is the name of the calling mode, e.g. `"bytes"`, or `"length"` for the default mode.
+fn check_kind_len(
+    rule: &'static str,
+    kind: ErrorKind,
+    len: usize,
+    min: usize,
+    max: usize,
+) -> Result<(), Error> {
+    let error = if min == max && len != min {
+        Error::localized(
+            kind,
+            "length_exact",
+            &[("len", &len), ("min", &min), ("max", &max)],
+            format_args!("length {len} is not exactly {min}"),
+        )
+    } else if len < min {
+        Error::localized(
+            kind,
+            "length_lower_than",
+            &[("len", &len), ("min", &min), ("max", &max)],
+            format_args!("length {len} is lower than minimum {min}"),
+        )
     } else if len > max {
-        Err(Error::new(format!("length is greater than {max}")))
+        Error::localized(
+            kind,
+            "length_greater_than",
+            &[("len", &len), ("min", &min), ("max", &max)],
+            format_args!("length {len} is greater than maximum {max}"),
+        )
     } else {
-        Ok(())
-    }
+        return Ok(());
+    };
+    Err(error.with_rule(rule).with_length(len))
 }
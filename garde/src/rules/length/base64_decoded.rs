@@ -0,0 +1,91 @@
+//! Implemented by string-like types holding base64 text, whose length is the number of bytes it decodes to.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(length(base64_decoded, max = 1024))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The decoded length is computed from the length of the text and its `=` padding, without decoding it.
+//! Both padded and unpadded text is accepted. The alphabet is not checked, so this works for both
+//! the standard and the URL-safe alphabet, but it does not guarantee that the text decodes successfully.
+//!
+//! Text whose length can't be produced by any base64 encoder fails validation, see [`decoded_len`].
+
+use core::ops::RangeInclusive;
+
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+use crate::rules::AsStr;
+
+pub fn apply<T: Base64Decoded>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    v.validate_num_decoded_bytes(min, max)
+}
+
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Base64Decoded>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
+/// Returns the number of bytes `encoded` decodes to, or `None` if its length is not a valid base64 length.
+///
+/// ```rust
+/// use garde::rules::length::base64_decoded::decoded_len;
+///
+/// assert_eq!(decoded_len("aGVsbG8="), Some(5));
+/// assert_eq!(decoded_len("aGVsbG8"), Some(5));
+/// assert_eq!(decoded_len("aGVsb"), None);
+/// ```
+pub fn decoded_len(encoded: &str) -> Option<usize> {
+    let bytes = encoded.as_bytes();
+    let padding = bytes.iter().rev().take_while(|&&b| b == b'=').count();
+    if padding > 2 || (padding > 0 && !bytes.len().is_multiple_of(4)) {
+        return None;
+    }
+    let unpadded = bytes.len() - padding;
+    // every 4 characters carry 3 bytes, and a trailing group of 2 or 3 characters carries 1 or 2 bytes
+    let rest = match unpadded % 4 {
+        0 => 0,
+        2 => 1,
+        3 => 2,
+        _ => return None,
+    };
+    Some(unpadded / 4 * 3 + rest)
+}
+
+pub trait Base64Decoded {
+    fn validate_num_decoded_bytes(&self, min: usize, max: usize) -> Result<(), Error>;
+}
+
+impl<T: AsStr> Base64Decoded for T {
+    fn validate_num_decoded_bytes(&self, min: usize, max: usize) -> Result<(), Error> {
+        match decoded_len(self.as_str()) {
+            Some(len) => super::check_kind_len(
+                "base64_decoded",
+                ErrorKind::Base64DecodedOutOfRange,
+                len,
+                min,
+                max,
+            ),
+            None => Err(Error::localized(
+                ErrorKind::Base64DecodedOutOfRange,
+                "base64_invalid_length",
+                &[],
+                format_args!("not a valid base64 length"),
+            )
+            .with_rule("base64_decoded")),
+        }
+    }
+}
+
+impl<T: Base64Decoded> Base64Decoded for Option<T> {
+    fn validate_num_decoded_bytes(&self, min: usize, max: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_num_decoded_bytes(min, max),
+            None => Ok(()),
+        }
+    }
+}
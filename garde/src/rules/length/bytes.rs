@@ -1,20 +1,39 @@
 //! Implemented by types for which we can retrieve the number of bytes.
 //!
 //! See also: [`chars` on `str`](https://doc.rust-lang.org/std/primitive.str.html#method.chars).
+//!
+//! With the `std` feature, this is also implemented for `OsStr`, `OsString`, `Path`, and `PathBuf`.
+//! Their length is the number of bytes in the platform-defined encoding used by [`OsStr`](https://doc.rust-lang.org/std/ffi/struct.OsStr.html#method.len),
+//! which is only guaranteed to match the byte length of the equivalent `str` for ASCII contents.
+
+use core::ops::RangeInclusive;
 
-use crate::error::Error;
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
 
-pub fn apply<T: Bytes>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+pub fn apply<T: Bytes>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
     v.validate_num_bytes(min, max)
 }
 
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Bytes>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
 pub trait Bytes {
     fn validate_num_bytes(&self, min: usize, max: usize) -> Result<(), Error>;
 }
 
 impl<T: HasBytes> Bytes for T {
     fn validate_num_bytes(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.num_bytes(), min, max)
+        super::check_kind_len(
+            "bytes",
+            ErrorKind::BytesOutOfRange,
+            self.num_bytes(),
+            min,
+            max,
+        )
     }
 }
 
@@ -31,6 +50,13 @@ pub trait HasBytes {
     fn num_bytes(&self) -> usize;
 }
 
+/// Mutable references have the length of the value they point to.
+impl<T: ?Sized + HasBytes> HasBytes for &mut T {
+    fn num_bytes(&self) -> usize {
+        T::num_bytes(self)
+    }
+}
+
 macro_rules! impl_via_len {
     ($(in<$lifetime:lifetime>)? $T:ty) => {
         impl<$($lifetime)?> HasBytes for $T {
@@ -43,19 +69,101 @@ macro_rules! impl_via_len {
 
 impl_via_len!(alloc::string::String);
 impl_via_len!(in<'a> &'a alloc::string::String);
+impl_via_len!(str);
 impl_via_len!(in<'a> &'a str);
 impl_via_len!(in<'a> alloc::borrow::Cow<'a, str>);
 impl_via_len!(alloc::rc::Rc<str>);
 impl_via_len!(alloc::sync::Arc<str>);
 impl_via_len!(alloc::boxed::Box<str>);
+impl_via_len!(in<'a> &'a alloc::boxed::Box<str>);
 impl_via_len!(in<'a> &'a [u8]);
 impl_via_len!(alloc::rc::Rc<[u8]>);
 impl_via_len!(alloc::sync::Arc<[u8]>);
 impl_via_len!(alloc::boxed::Box<[u8]>);
 impl_via_len!(alloc::vec::Vec<u8>);
+impl_via_len!(in<'a> alloc::borrow::Cow<'a, [u8]>);
+impl_via_len!(compact_str::CompactString);
+impl_via_len!(in<'a> &'a compact_str::CompactString);
+
+#[cfg(feature = "bytes")]
+impl_via_len!(bytes::Bytes);
+#[cfg(feature = "bytes")]
+impl_via_len!(bytes::BytesMut);
+
+impl<'a, 'b> HasBytes for &'a alloc::borrow::Cow<'b, str> {
+    fn num_bytes(&self) -> usize {
+        self.len()
+    }
+}
 
 impl<const N: usize> HasBytes for [u8; N] {
     fn num_bytes(&self) -> usize {
         self.len()
     }
 }
+
+impl<const N: usize> HasBytes for smallvec::SmallVec<u8, N> {
+    fn num_bytes(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> HasBytes for arrayvec::ArrayString<CAP> {
+    fn num_bytes(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> HasBytes for heapless::String<N> {
+    fn num_bytes(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<N: generic_array::ArrayLength> HasBytes for generic_array::GenericArray<u8, N> {
+    fn num_bytes(&self) -> usize {
+        self.len()
+    }
+}
+
+/// The trailing nul terminator is not counted.
+impl HasBytes for alloc::ffi::CString {
+    fn num_bytes(&self) -> usize {
+        self.to_bytes().len()
+    }
+}
+
+/// The trailing nul terminator is not counted.
+impl HasBytes for &core::ffi::CStr {
+    fn num_bytes(&self) -> usize {
+        self.to_bytes().len()
+    }
+}
+
+#[cfg(feature = "std")]
+impl_via_len!(std::ffi::OsString);
+#[cfg(feature = "std")]
+impl_via_len!(in<'a> &'a std::ffi::OsString);
+#[cfg(feature = "std")]
+impl_via_len!(in<'a> &'a std::ffi::OsStr);
+
+#[cfg(feature = "std")]
+macro_rules! impl_via_os_str {
+    ($(in<$lifetime:lifetime>)? $T:ty) => {
+        impl<$($lifetime)?> HasBytes for $T {
+            fn num_bytes(&self) -> usize {
+                self.as_os_str().len()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_via_os_str!(std::path::PathBuf);
+#[cfg(feature = "std")]
+impl_via_os_str!(in<'a> &'a std::path::PathBuf);
+#[cfg(feature = "std")]
+impl_via_os_str!(in<'a> &'a std::path::Path);
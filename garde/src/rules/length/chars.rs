@@ -1,20 +1,40 @@
 //! Implemented by string-like types for which we can retrieve the number of [Unicode Scalar Values](https://www.unicode.org/glossary/#unicode_scalar_value).
 //!
 //! See also: [`chars` on `str`](https://doc.rust-lang.org/std/primitive.str.html#method.chars).
+//!
+//! Counting stops after `max + 1` chars, so a long string checked against a small maximum is not decoded in full.
+//! When a value is longer than that, the error does not include its exact length.
+
+use core::ops::RangeInclusive;
 
-use crate::error::Error;
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
 
-pub fn apply<T: Chars>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+pub fn apply<T: Chars>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
     v.validate_num_chars(min, max)
 }
 
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Chars>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
 pub trait Chars {
     fn validate_num_chars(&self, min: usize, max: usize) -> Result<(), Error>;
 }
 
 impl<T: HasChars> Chars for T {
     fn validate_num_chars(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.num_chars(), min, max)
+        match self.num_chars_up_to(max.saturating_add(1)) {
+            Some(len) => super::check_kind_len("chars", ErrorKind::CharsOutOfRange, len, min, max),
+            None => Err(super::greater_than_max(
+                "chars",
+                ErrorKind::CharsOutOfRange,
+                min,
+                max,
+            )),
+        }
     }
 }
 
@@ -29,13 +49,68 @@ impl<T: Chars> Chars for Option<T> {
 
 pub trait HasChars {
     fn num_chars(&self) -> usize;
+
+    /// Same as [`HasChars::num_chars`], but returns `None` as soon as the count exceeds `limit`.
+    ///
+    /// The default implementation counts every char. Implementations which iterate over their
+    /// contents should override it, for example using [`count_up_to`].
+    fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+        let len = self.num_chars();
+        (len <= limit).then_some(len)
+    }
+}
+
+impl<T: ?Sized + HasChars> HasChars for &mut T {
+    fn num_chars(&self) -> usize {
+        T::num_chars(self)
+    }
+
+    fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+        T::num_chars_up_to(self, limit)
+    }
+}
+
+/// Counts the items of `iter`, returning `None` once it yields more than `limit` items.
+pub fn count_up_to<I: Iterator>(iter: I, limit: usize) -> Option<usize> {
+    let len = iter.take(limit.saturating_add(1)).count();
+    (len <= limit).then_some(len)
+}
+
+/// ASCII strings have exactly one char per byte, which is much cheaper to check than to decode.
+#[inline]
+fn count_chars(s: &str) -> usize {
+    if s.is_ascii() {
+        s.len()
+    } else {
+        s.chars().count()
+    }
+}
+
+/// A string has at most one char per byte, so it only has to be decoded in full when it is short.
+/// Otherwise, the byte index after the `limit`th char tells whether there are any more chars.
+#[inline]
+fn count_chars_up_to(s: &str, limit: usize) -> Option<usize> {
+    if s.len() <= limit {
+        return Some(count_chars(s));
+    }
+    let mut len = 0;
+    let mut end = 0;
+    for (index, c) in s.char_indices().take(limit) {
+        len += 1;
+        end = index + c.len_utf8();
+    }
+    (end == s.len()).then_some(len)
 }
 
 macro_rules! impl_via_chars {
     ($(in <$lifetime:lifetime>)? $T:ty) => {
         impl<$($lifetime)?> HasChars for $T {
             fn num_chars(&self) -> usize {
-                self.chars().count()
+                count_chars(self)
+            }
+
+            fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+                count_chars_up_to(self, limit)
             }
         }
     };
@@ -43,11 +118,47 @@ macro_rules! impl_via_chars {
 
 impl_via_chars!(alloc::string::String);
 impl_via_chars!(in<'a> &'a alloc::string::String);
+impl_via_chars!(str);
 impl_via_chars!(in<'a> &'a str);
 impl_via_chars!(in<'a> alloc::borrow::Cow<'a, str>);
 impl_via_chars!(alloc::rc::Rc<str>);
 impl_via_chars!(alloc::sync::Arc<str>);
 impl_via_chars!(alloc::boxed::Box<str>);
+impl_via_chars!(in<'a> &'a alloc::boxed::Box<str>);
+impl_via_chars!(compact_str::CompactString);
+impl_via_chars!(in<'a> &'a compact_str::CompactString);
+
+impl<'a, 'b> HasChars for &'a alloc::borrow::Cow<'b, str> {
+    fn num_chars(&self) -> usize {
+        count_chars(self)
+    }
+
+    fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+        count_chars_up_to(self, limit)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> HasChars for arrayvec::ArrayString<CAP> {
+    fn num_chars(&self) -> usize {
+        count_chars(self)
+    }
+
+    fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+        count_chars_up_to(self, limit)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> HasChars for heapless::String<N> {
+    fn num_chars(&self) -> usize {
+        count_chars(self)
+    }
+
+    fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+        count_chars_up_to(self, limit)
+    }
+}
 
 macro_rules! impl_via_len {
     ($(in<$lifetime:lifetime>)? $T:ty) => {
@@ -64,3 +175,9 @@ impl_via_len!(alloc::sync::Arc<[char]>);
 impl_via_len!(alloc::rc::Rc<[char]>);
 impl_via_len!(alloc::boxed::Box<[char]>);
 impl_via_len!(alloc::vec::Vec<char>);
+
+impl<const N: usize> HasChars for smallvec::SmallVec<char, N> {
+    fn num_chars(&self) -> usize {
+        self.len()
+    }
+}
@@ -0,0 +1,77 @@
+//! Implemented by types which can produce an iterator over their items, but do not expose a length.
+//!
+//! The count is the number of items yielded by the iterator, so checking it takes linear time.
+//!
+//! To use `length(count)` with a custom type, implement [`Countable`] for it:
+//! ```rust
+//! use garde::rules::length::Countable;
+//!
+//! struct Tags(Vec<&'static str>);
+//!
+//! impl Countable for Tags {
+//!     type Iter<'a> = core::slice::Iter<'a, &'static str>;
+//!
+//!     fn iter(&self) -> Self::Iter<'_> {
+//!         self.0.iter()
+//!     }
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Post {
+//!     #[garde(length(count, min = 1, max = 5))]
+//!     tags: Tags,
+//! }
+//! ```
+
+use core::ops::RangeInclusive;
+
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Count>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    v.validate_count(min, max)
+}
+
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Count>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
+pub trait Count {
+    fn validate_count(&self, min: usize, max: usize) -> Result<(), Error>;
+}
+
+impl<T: HasCount> Count for T {
+    fn validate_count(&self, min: usize, max: usize) -> Result<(), Error> {
+        super::check_kind_len("count", ErrorKind::CountOutOfRange, self.count(), min, max)
+    }
+}
+
+impl<T: Count> Count for Option<T> {
+    fn validate_count(&self, min: usize, max: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_count(min, max),
+            None => Ok(()),
+        }
+    }
+}
+
+pub trait HasCount {
+    fn count(&self) -> usize;
+}
+
+/// A type which can produce an iterator over its items.
+pub trait Countable {
+    type Iter<'a>: Iterator
+    where
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+impl<T: Countable> HasCount for T {
+    fn count(&self) -> usize {
+        Countable::iter(self).count()
+    }
+}
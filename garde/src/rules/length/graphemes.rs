@@ -2,19 +2,34 @@
 //!
 //! `garde` implementations of this trait use the [unicode-segmentation](https://crates.io/crates/unicode-segmentation) crate.
 
-use crate::error::Error;
+use core::ops::RangeInclusive;
 
-pub fn apply<T: Graphemes>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Graphemes>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
     v.validate_num_graphemes(min, max)
 }
 
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Graphemes>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
 pub trait Graphemes {
     fn validate_num_graphemes(&self, min: usize, max: usize) -> Result<(), Error>;
 }
 
 impl<T: HasGraphemes> Graphemes for T {
     fn validate_num_graphemes(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.num_graphemes(), min, max)
+        super::check_kind_len(
+            "graphemes",
+            ErrorKind::GraphemesOutOfRange,
+            self.num_graphemes(),
+            min,
+            max,
+        )
     }
 }
 
@@ -48,5 +63,5 @@ impl_str!(in<'a> &'a alloc::string::String);
 impl_str!(in<'a> &'a str);
 impl_str!(in<'a> alloc::borrow::Cow<'a, str>);
 impl_str!(alloc::rc::Rc<str>);
-impl_str!(core::sync::Arc<str>);
+impl_str!(alloc::sync::Arc<str>);
 impl_str!(alloc::boxed::Box<str>);
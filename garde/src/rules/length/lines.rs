@@ -0,0 +1,69 @@
+//! Implemented by string-like types for which we can retrieve the number of lines.
+//!
+//! Lines are counted using [`lines`](https://doc.rust-lang.org/std/primitive.str.html#method.lines),
+//! which means that:
+//! - an empty string has zero lines,
+//! - a single line without a line ending has one line,
+//! - a trailing line ending (`\n` or `\r\n`) does not produce an extra empty line.
+
+use core::ops::RangeInclusive;
+
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Lines>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    v.validate_num_lines(min, max)
+}
+
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Lines>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
+pub trait Lines {
+    fn validate_num_lines(&self, min: usize, max: usize) -> Result<(), Error>;
+}
+
+impl<T: HasLines> Lines for T {
+    fn validate_num_lines(&self, min: usize, max: usize) -> Result<(), Error> {
+        super::check_kind_len(
+            "lines",
+            ErrorKind::LinesOutOfRange,
+            self.num_lines(),
+            min,
+            max,
+        )
+    }
+}
+
+impl<T: Lines> Lines for Option<T> {
+    fn validate_num_lines(&self, min: usize, max: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_num_lines(min, max),
+            None => Ok(()),
+        }
+    }
+}
+
+pub trait HasLines {
+    fn num_lines(&self) -> usize;
+}
+
+macro_rules! impl_str {
+    ($(in<$lifetime:lifetime>)? $T:ty) => {
+        impl<$($lifetime)?> HasLines for $T {
+            fn num_lines(&self) -> usize {
+                self.lines().count()
+            }
+        }
+    };
+}
+
+impl_str!(alloc::string::String);
+impl_str!(in<'a> &'a alloc::string::String);
+impl_str!(in<'a> &'a str);
+impl_str!(in<'a> alloc::borrow::Cow<'a, str>);
+impl_str!(alloc::rc::Rc<str>);
+impl_str!(alloc::sync::Arc<str>);
+impl_str!(alloc::boxed::Box<str>);
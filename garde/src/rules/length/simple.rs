@@ -4,19 +4,34 @@
 //! For example, the length of a `String` is defined as the number of _bytes_ it stores.
 
 use alloc::vec::Vec;
-use crate::error::Error;
+use core::ops::RangeInclusive;
 
-pub fn apply<T: Simple>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Simple>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
     v.validate_length(min, max)
 }
 
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Simple>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
 pub trait Simple {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error>;
 }
 
 impl<T: HasSimpleLength> Simple for T {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.length(), min, max)
+        super::check_kind_len(
+            "length",
+            ErrorKind::LengthOutOfRange,
+            self.length(),
+            min,
+            max,
+        )
     }
 }
 
@@ -33,6 +48,19 @@ pub trait HasSimpleLength {
     fn length(&self) -> usize;
 }
 
+/// References have the length of the value they point to.
+impl<T: ?Sized + HasSimpleLength> HasSimpleLength for &T {
+    fn length(&self) -> usize {
+        T::length(self)
+    }
+}
+
+impl<T: ?Sized + HasSimpleLength> HasSimpleLength for &mut T {
+    fn length(&self) -> usize {
+        T::length(self)
+    }
+}
+
 macro_rules! impl_via_bytes {
     ($(in<$lifetime:lifetime>)? $T:ty) => {
         impl<$($lifetime)?> HasSimpleLength for $T {
@@ -45,13 +73,49 @@ macro_rules! impl_via_bytes {
 }
 
 impl_via_bytes!(alloc::string::String);
-impl_via_bytes!(in<'a> &'a alloc::string::String);
-impl_via_bytes!(in<'a> &'a str);
+impl_via_bytes!(str);
 impl_via_bytes!(in<'a> alloc::borrow::Cow<'a, str>);
 impl_via_bytes!(alloc::rc::Rc<str>);
 impl_via_bytes!(alloc::sync::Arc<str>);
 impl_via_bytes!(alloc::boxed::Box<str>);
 
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> HasSimpleLength for arrayvec::ArrayString<CAP> {
+    fn length(&self) -> usize {
+        use super::bytes::HasBytes as _;
+        self.num_bytes()
+    }
+}
+#[cfg(feature = "heapless")]
+impl<const N: usize> HasSimpleLength for heapless::String<N> {
+    fn length(&self) -> usize {
+        use super::bytes::HasBytes as _;
+        self.num_bytes()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl_via_bytes!(bytes::Bytes);
+#[cfg(feature = "bytes")]
+impl_via_bytes!(bytes::BytesMut);
+
+#[cfg(feature = "std")]
+impl_via_bytes!(std::ffi::OsString);
+#[cfg(feature = "std")]
+impl HasSimpleLength for std::ffi::OsStr {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+#[cfg(feature = "std")]
+impl_via_bytes!(std::path::PathBuf);
+#[cfg(feature = "std")]
+impl HasSimpleLength for std::path::Path {
+    fn length(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
 macro_rules! impl_via_len {
     (in<$lifetime:lifetime, $($generic:ident),*> $T:ty) => {
         impl<$lifetime, $($generic),*> HasSimpleLength for $T {
@@ -84,32 +148,120 @@ macro_rules! impl_via_len {
 }
 
 impl_via_len!(in<T> Vec<T>);
-impl_via_len!(in<'a, T> &'a Vec<T>);
-impl_via_len!(in<'a, T> &'a [T]);
+impl_via_len!(in<T> [T]);
+impl_via_len!(in<T> alloc::boxed::Box<[T]>);
+impl_via_len!(in<T> alloc::rc::Rc<[T]>);
+impl_via_len!(in<T> alloc::sync::Arc<[T]>);
+
+impl<'a, T: Clone> HasSimpleLength for alloc::borrow::Cow<'a, [T]> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<const N: usize, T> HasSimpleLength for smallvec::SmallVec<T, N> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
 
 impl<const N: usize, T> Simple for [T; N] {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.len(), min, max)
+        super::check_kind_len("length", ErrorKind::LengthOutOfRange, self.len(), min, max)
     }
 }
 
-impl<'a, const N: usize, T> Simple for &'a [T; N] {
+impl<const N: usize, T> Simple for &[T; N] {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.len(), min, max)
+        super::check_kind_len("length", ErrorKind::LengthOutOfRange, self.len(), min, max)
     }
 }
 
-// impl_via_len!(in<K, V, S> alloc::collections::HashMap<K, V, S>);
-// impl_via_len!(in<T, S> alloc::collections::HashSet<T, S>);
+impl<const N: usize, T> Simple for &mut [T; N] {
+    fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
+        super::check_kind_len("length", ErrorKind::LengthOutOfRange, self.len(), min, max)
+    }
+}
+
+impl<const N: usize, T> Simple for alloc::boxed::Box<[T; N]> {
+    fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
+        super::check_kind_len("length", ErrorKind::LengthOutOfRange, self.len(), min, max)
+    }
+}
+
+/// The length of a tuple is its arity.
+macro_rules! impl_via_arity {
+    ($($T:ident)+) => {
+        impl<$($T),+> HasSimpleLength for ($($T,)+) {
+            fn length(&self) -> usize {
+                [$(impl_via_arity!(@unit $T)),+].len()
+            }
+        }
+    };
+    (@unit $T:ident) => {
+        ()
+    };
+}
+
+impl_via_arity!(A);
+impl_via_arity!(A B);
+impl_via_arity!(A B C);
+impl_via_arity!(A B C D);
+impl_via_arity!(A B C D E);
+impl_via_arity!(A B C D E F);
+impl_via_arity!(A B C D E F G);
+impl_via_arity!(A B C D E F G H);
+impl_via_arity!(A B C D E F G H I);
+impl_via_arity!(A B C D E F G H I J);
+impl_via_arity!(A B C D E F G H I J K);
+impl_via_arity!(A B C D E F G H I J K L);
+
 impl_via_len!(in<K, V> alloc::collections::BTreeMap<K, V>);
 impl_via_len!(in<T> alloc::collections::BTreeSet<T>);
 impl_via_len!(in<T> alloc::collections::VecDeque<T>);
 impl_via_len!(in<T> alloc::collections::BinaryHeap<T>);
 impl_via_len!(in<T> alloc::collections::LinkedList<T>);
-// impl_via_len!(in<'a, K, V, S> &'a alloc::collections::HashMap<K, V, S>);
-// impl_via_len!(in<'a, T, S> &'a alloc::collections::HashSet<T, S>);
-impl_via_len!(in<'a, K, V> &'a alloc::collections::BTreeMap<K, V>);
-impl_via_len!(in<'a, T> &'a alloc::collections::BTreeSet<T>);
-impl_via_len!(in<'a, T> &'a alloc::collections::VecDeque<T>);
-impl_via_len!(in<'a, T> &'a alloc::collections::BinaryHeap<T>);
-impl_via_len!(in<'a, T> &'a alloc::collections::LinkedList<T>);
+
+/// The length of a range is the number of indices it spans, `end - start`.
+/// An inverted range such as `7..2` is empty, and has a length of `0` rather than underflowing.
+impl HasSimpleLength for core::ops::Range<usize> {
+    fn length(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl_via_len!(in<K, V, S> hashbrown::HashMap<K, V, S>);
+#[cfg(feature = "hashbrown")]
+impl_via_len!(in<T, S> hashbrown::HashSet<T, S>);
+
+#[cfg(feature = "indexmap")]
+impl_via_len!(in<K, V, S> indexmap::IndexMap<K, V, S>);
+#[cfg(feature = "indexmap")]
+impl_via_len!(in<T, S> indexmap::IndexSet<T, S>);
+
+#[cfg(feature = "std")]
+impl_via_len!(in<K, V, S> std::collections::HashMap<K, V, S>);
+#[cfg(feature = "std")]
+impl_via_len!(in<T, S> std::collections::HashSet<T, S>);
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize, T> HasSimpleLength for arrayvec::ArrayVec<T, CAP> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize, T> HasSimpleLength for heapless::Vec<T, N> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<T, N: generic_array::ArrayLength> HasSimpleLength for generic_array::GenericArray<T, N> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
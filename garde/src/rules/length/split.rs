@@ -0,0 +1,46 @@
+//! Implemented by string-like types whose length is the number of segments between a delimiter.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(length(split = ",", min = 1, max = 5))]
+//!     tags: String,
+//! }
+//! ```
+//!
+//! Segments are counted with [`str::split`], so every segment counts, even an empty one:
+//! - `"a,b,c"` has 3 segments
+//! - `"a,"` has 2 segments, the trailing one being empty
+//! - `""` has 1 empty segment
+//!
+//! The count is therefore never zero, so `min = 1` accepts empty strings.
+//! Combine it with the [`non_empty`][crate::rules::non_empty] rule to reject them.
+
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+use crate::rules::AsStr;
+
+pub fn apply<T: Split>(v: &T, (delimiter, bounds): (&str, impl Into<Bounds>)) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    v.validate_num_segments(delimiter, min, max)
+}
+
+pub trait Split {
+    fn validate_num_segments(&self, delimiter: &str, min: usize, max: usize) -> Result<(), Error>;
+}
+
+impl<T: AsStr> Split for T {
+    fn validate_num_segments(&self, delimiter: &str, min: usize, max: usize) -> Result<(), Error> {
+        let len = self.as_str().split(delimiter).count();
+        super::check_kind_len("split", ErrorKind::SegmentsOutOfRange, len, min, max)
+    }
+}
+
+impl<T: Split> Split for Option<T> {
+    fn validate_num_segments(&self, delimiter: &str, min: usize, max: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_num_segments(delimiter, min, max),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Implemented by string-like types whose length is measured after trimming surrounding whitespace.
+//!
+//! Whitespace is defined by [`str::trim`], which uses the Unicode `White_Space` property.
+//! The trimmed string is then measured according to a [`LengthMode`]:
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(length(trimmed, chars, min = 1, max = 10))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Without an explicit mode, the number of bytes is validated, like the `simple` mode does for strings.
+
+use super::{Bounds, LengthMode};
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: Trimmed>(
+    v: &T,
+    (mode, bounds): (LengthMode, impl Into<Bounds>),
+) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    v.validate_trimmed_length(mode, min, max)
+}
+
+pub trait Trimmed {
+    fn validate_trimmed_length(
+        &self,
+        mode: LengthMode,
+        min: usize,
+        max: usize,
+    ) -> Result<(), Error>;
+}
+
+impl<T: AsStr> Trimmed for T {
+    fn validate_trimmed_length(
+        &self,
+        mode: LengthMode,
+        min: usize,
+        max: usize,
+    ) -> Result<(), Error> {
+        super::apply_with_mode(&self.as_str().trim(), (min, max), mode)
+    }
+}
+
+impl<T: Trimmed> Trimmed for Option<T> {
+    fn validate_trimmed_length(
+        &self,
+        mode: LengthMode,
+        min: usize,
+        max: usize,
+    ) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_trimmed_length(mode, min, max),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,18 +1,33 @@
 //! Implemented by string-like types for which we can retrieve length in _UTF-16 code units_.
 
-use crate::error::Error;
+use core::ops::RangeInclusive;
 
-pub fn apply<T: Utf16CodeUnits>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Utf16CodeUnits>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
     v.validate_num_code_units(min, max)
 }
 
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Utf16CodeUnits>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
 pub trait Utf16CodeUnits {
     fn validate_num_code_units(&self, min: usize, max: usize) -> Result<(), Error>;
 }
 
 impl<T: HasUtf16CodeUnits> Utf16CodeUnits for T {
     fn validate_num_code_units(&self, min: usize, max: usize) -> Result<(), Error> {
-        super::check_len(self.num_code_units(), min, max)
+        super::check_kind_len(
+            "utf16",
+            ErrorKind::Utf16OutOfRange,
+            self.num_code_units(),
+            min,
+            max,
+        )
     }
 }
 
@@ -0,0 +1,67 @@
+//! Implemented by string-like types for which we can retrieve the number of whitespace-separated words.
+//!
+//! Words are counted using [`split_whitespace`](https://doc.rust-lang.org/std/primitive.str.html#method.split_whitespace),
+//! so leading and trailing whitespace is ignored, runs of whitespace count as a single separator,
+//! and an empty or whitespace-only string has zero words.
+
+use core::ops::RangeInclusive;
+
+use super::Bounds;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Words>(v: &T, bounds: impl Into<Bounds>) -> Result<(), Error> {
+    let Bounds { min, max } = bounds.into();
+    v.validate_num_words(min, max)
+}
+
+/// Same as [`apply`], with the bounds given as `min..=max`.
+pub fn apply_range<T: Words>(v: &T, bounds: RangeInclusive<usize>) -> Result<(), Error> {
+    apply(v, bounds)
+}
+
+pub trait Words {
+    fn validate_num_words(&self, min: usize, max: usize) -> Result<(), Error>;
+}
+
+impl<T: HasWords> Words for T {
+    fn validate_num_words(&self, min: usize, max: usize) -> Result<(), Error> {
+        super::check_kind_len(
+            "words",
+            ErrorKind::WordsOutOfRange,
+            self.num_words(),
+            min,
+            max,
+        )
+    }
+}
+
+impl<T: Words> Words for Option<T> {
+    fn validate_num_words(&self, min: usize, max: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_num_words(min, max),
+            None => Ok(()),
+        }
+    }
+}
+
+pub trait HasWords {
+    fn num_words(&self) -> usize;
+}
+
+macro_rules! impl_str {
+    ($(in<$lifetime:lifetime>)? $T:ty) => {
+        impl<$($lifetime)?> HasWords for $T {
+            fn num_words(&self) -> usize {
+                self.split_whitespace().count()
+            }
+        }
+    };
+}
+
+impl_str!(alloc::string::String);
+impl_str!(in<'a> &'a alloc::string::String);
+impl_str!(in<'a> &'a str);
+impl_str!(in<'a> alloc::borrow::Cow<'a, str>);
+impl_str!(alloc::rc::Rc<str>);
+impl_str!(alloc::sync::Arc<str>);
+impl_str!(alloc::boxed::Box<str>);
@@ -0,0 +1,64 @@
+//! Lowercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(lowercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Fails if any character is [`uppercase`](char::is_uppercase). Characters without case, such as digits or punctuation, are ignored.
+//!
+//! The entrypoint is the [`Lowercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(lowercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Lowercase>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(NotLowercase { index, character }) = v.validate_lowercase() {
+        return Err(Error::localized(
+            ErrorKind::NotLowercase,
+            "lowercase",
+            &[("character", &character), ("index", &index)],
+            format_args!("not lowercase, found {character:?} at index {index}"),
+        )
+        .with_rule("lowercase"));
+    }
+    Ok(())
+}
+
+pub trait Lowercase {
+    fn validate_lowercase(&self) -> Result<(), NotLowercase>;
+}
+
+/// The first offending character, and its index in terms of `char`s.
+pub struct NotLowercase {
+    pub index: usize,
+    pub character: char,
+}
+
+impl<T: AsStr> Lowercase for T {
+    fn validate_lowercase(&self) -> Result<(), NotLowercase> {
+        match self
+            .as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| c.is_uppercase())
+        {
+            Some((index, character)) => Err(NotLowercase { index, character }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Lowercase> Lowercase for Option<T> {
+    fn validate_lowercase(&self) -> Result<(), NotLowercase> {
+        match self {
+            Some(value) => value.validate_lowercase(),
+            None => Ok(()),
+        }
+    }
+}
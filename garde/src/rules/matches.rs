@@ -14,12 +14,17 @@
 //!
 //! This trait has a blanket implementation for all `T: PartialEq<O>, O`.
 
-use alloc::format;
-use crate::Error;
+use crate::{Error, ErrorKind};
 
 pub fn apply<T: Matches<O>, O>(v: &T, (field, value): (&str, &O)) -> Result<(), Error> {
     if !v.validate_matches(value) {
-        return Err(Error::new(format!("does not match {field} field")));
+        return Err(Error::localized(
+            ErrorKind::FieldMismatch,
+            "matches",
+            &[("field", &field)],
+            format_args!("does not match {field} field"),
+        )
+        .with_rule("matches"));
     }
     Ok(())
 }
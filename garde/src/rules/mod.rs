@@ -4,30 +4,47 @@ use alloc::string::String;
 
 pub mod alphanumeric;
 pub mod ascii;
+pub mod ascii_alphanumeric;
+pub mod bits;
+pub mod char_range;
 pub mod contains;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
+pub mod datetime;
 #[cfg(feature = "email")]
 pub mod email;
+pub mod hex;
 pub mod inner;
 pub mod ip;
+pub mod keys;
 pub mod length;
+pub mod lowercase;
 pub mod matches;
+pub mod multiple_of;
+pub mod non_empty;
+pub mod not_contains;
+pub mod one_of;
 pub mod pattern;
+pub mod phone;
 #[cfg(feature = "phone-number")]
 pub mod phone_number;
 pub mod prefix;
+pub mod prefix_any;
 pub mod range;
 pub mod required;
 pub mod suffix;
-#[cfg(feature = "url")]
+pub mod suffix_any;
+pub mod text_hygiene;
+pub mod uppercase;
+#[cfg(all(feature = "url", feature = "std"))]
 pub mod url;
+pub mod uuid;
 
 pub trait AsStr {
     fn as_str(&self) -> &str;
 }
 
-impl<'a> AsStr for &'a str {
+impl AsStr for &str {
     fn as_str(&self) -> &str {
         self
     }
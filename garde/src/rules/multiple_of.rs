@@ -0,0 +1,67 @@
+//! Divisibility validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(multiple_of(5))]
+//!     cents: u64,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`MultipleOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(multiple_of(...))]` rule.
+//!
+//! This trait is implemented for all primitive integer types.
+//! Negative values are multiples if their magnitude is, so `-10` is a multiple of both `5` and `-5`.
+//!
+//! A literal zero divisor is rejected by the derive macro. A zero divisor computed at runtime fails validation for every value.
+
+use core::fmt::Display;
+
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: MultipleOf>(v: &T, (divisor,): (T::Divisor,)) -> Result<(), Error> {
+    if !v.validate_multiple_of(divisor) {
+        return Err(Error::localized(
+            ErrorKind::NotMultipleOf,
+            "multiple_of",
+            &[("divisor", &divisor)],
+            format_args!("not a multiple of {divisor}"),
+        )
+        .with_rule("multiple_of"));
+    }
+    Ok(())
+}
+
+pub trait MultipleOf {
+    type Divisor: Copy + Display;
+
+    fn validate_multiple_of(&self, divisor: Self::Divisor) -> bool;
+}
+
+macro_rules! impl_for_int {
+    ($($T:ident),*) => {
+        $(
+            impl MultipleOf for $T {
+                type Divisor = $T;
+
+                fn validate_multiple_of(&self, divisor: Self::Divisor) -> bool {
+                    // `wrapping_rem` only wraps for `MIN % -1`, which is `0` either way.
+                    divisor != 0 && self.wrapping_rem(divisor) == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: MultipleOf> MultipleOf for Option<T> {
+    type Divisor = T::Divisor;
+
+    fn validate_multiple_of(&self, divisor: Self::Divisor) -> bool {
+        match self {
+            Some(value) => value.validate_multiple_of(divisor),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Non-empty validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(non_empty)]
+//!     v: Vec<u32>,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`NonEmpty`] trait. It is implemented for every type which implements [`HasSimpleLength`],
+//! so `#[garde(non_empty)]` is equivalent to `#[garde(length(min = 1))]`.
+
+use super::length::HasSimpleLength;
+use crate::{Error, ErrorKind, Result};
+
+pub fn apply<T: NonEmpty>(v: &T, _: ()) -> Result {
+    if v.is_empty() {
+        return Err(Error::localized(
+            ErrorKind::Empty,
+            "non_empty",
+            &[],
+            format_args!("must not be empty"),
+        )
+        .with_rule("non_empty"));
+    }
+    Ok(())
+}
+
+pub trait NonEmpty {
+    fn is_empty(&self) -> bool;
+}
+
+impl<T: HasSimpleLength> NonEmpty for T {
+    fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+}
+
+impl<T: NonEmpty> NonEmpty for Option<T> {
+    fn is_empty(&self) -> bool {
+        match self {
+            Some(v) => v.is_empty(),
+            None => false,
+        }
+    }
+}
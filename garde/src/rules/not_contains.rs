@@ -0,0 +1,48 @@
+//! Forbidden substring validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not_contains("--"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`NotContains`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(not_contains)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: NotContains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
+    if !v.validate_not_contains(pat) {
+        return Err(Error::localized(
+            ErrorKind::ForbiddenSubstring,
+            "not_contains",
+            &[("pattern", &pat)],
+            format_args!("contains \"{pat}\""),
+        )
+        .with_rule("not_contains"));
+    }
+    Ok(())
+}
+
+pub trait NotContains {
+    fn validate_not_contains(&self, pat: &str) -> bool;
+}
+
+impl<T: AsStr> NotContains for T {
+    fn validate_not_contains(&self, pat: &str) -> bool {
+        !self.as_str().contains(pat)
+    }
+}
+
+impl<T: NotContains> NotContains for Option<T> {
+    fn validate_not_contains(&self, pat: &str) -> bool {
+        match self {
+            Some(value) => value.validate_not_contains(pat),
+            None => true,
+        }
+    }
+}
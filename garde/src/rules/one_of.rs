@@ -0,0 +1,68 @@
+//! Membership validation.
+//!
+//! ```rust
+//! const BLUE: &str = "blue";
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(one_of("red", "green", BLUE))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`OneOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(one_of)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use core::fmt::{Display, Formatter};
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: OneOf>(v: &T, (values,): (&[&str],)) -> Result<(), Error> {
+    if !v.validate_one_of(values) {
+        let values = Values(values);
+        return Err(Error::localized(
+            ErrorKind::NotOneOf,
+            "one_of",
+            &[("values", &values)],
+            format_args!("not one of {values}"),
+        )
+        .with_rule("one_of"));
+    }
+    Ok(())
+}
+
+pub trait OneOf {
+    fn validate_one_of(&self, values: &[&str]) -> bool;
+}
+
+impl<T: AsStr> OneOf for T {
+    fn validate_one_of(&self, values: &[&str]) -> bool {
+        values.contains(&self.as_str())
+    }
+}
+
+impl<T: OneOf> OneOf for Option<T> {
+    fn validate_one_of(&self, values: &[&str]) -> bool {
+        match self {
+            Some(value) => value.validate_one_of(values),
+            None => true,
+        }
+    }
+}
+
+/// Displays the allowed values as a comma-separated list of quoted strings.
+pub(crate) struct Values<'a>(pub(crate) &'a [&'a str]);
+
+impl Display for Values<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        Ok(())
+    }
+}
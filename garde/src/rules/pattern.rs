@@ -32,16 +32,18 @@
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
-use alloc::format;
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error> {
     if !v.validate_pattern(pat) {
-        return Err(Error::new(format!(
-            "does not match pattern /{}/",
-            pat.as_str()
-        )));
+        return Err(Error::localized(
+            ErrorKind::PatternMismatch,
+            "pattern",
+            &[("pattern", &pat.as_str())],
+            format_args!("does not match pattern /{}/", pat.as_str()),
+        )
+        .with_rule("pattern"));
     }
     Ok(())
 }
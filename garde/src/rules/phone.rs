@@ -0,0 +1,56 @@
+//! Structural phone number validation, following the [E.164](https://en.wikipedia.org/wiki/E.164) format.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(phone)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A valid value is an optional leading `+`, followed by 1 to 15 digits, the first of which is not `0`.
+//! Separators such as spaces or dashes are not accepted. Unlike [`phone_number`](crate::rules::phone_number),
+//! this does not check that the number is actually assigned, and needs no extra dependency.
+//!
+//! The entrypoint is the [`Phone`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(phone)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Phone>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_phone() {
+        return Err(Error::localized(
+            ErrorKind::InvalidPhoneNumber,
+            "phone",
+            &[],
+            format_args!("not a valid E.164 phone number"),
+        )
+        .with_rule("phone"));
+    }
+    Ok(())
+}
+
+pub trait Phone {
+    fn validate_phone(&self) -> bool;
+}
+
+impl<T: AsStr> Phone for T {
+    fn validate_phone(&self) -> bool {
+        let value = self.as_str();
+        let digits = value.strip_prefix('+').unwrap_or(value).as_bytes();
+        matches!(digits.first(), Some(b'1'..=b'9'))
+            && digits.len() <= 15
+            && digits.iter().all(u8::is_ascii_digit)
+    }
+}
+
+impl<T: Phone> Phone for Option<T> {
+    fn validate_phone(&self) -> bool {
+        match self {
+            Some(value) => value.validate_phone(),
+            None => true,
+        }
+    }
+}
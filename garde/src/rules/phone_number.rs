@@ -12,18 +12,29 @@
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
-use alloc::format;
 use core::fmt::Display;
 use core::str::FromStr;
 
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: PhoneNumber>(v: &T, _: ()) -> Result<(), Error> {
     match v.validate_phone_number() {
         Ok(true) => Ok(()),
-        Ok(false) => Err(Error::new("not a valid phone number")),
-        Err(e) => Err(Error::new(format!("not a valid phone number: {e}"))),
+        Ok(false) => Err(Error::localized(
+            ErrorKind::InvalidPhoneNumber,
+            "phone_number",
+            &[],
+            format_args!("not a valid phone number"),
+        )
+        .with_rule("phone_number")),
+        Err(e) => Err(Error::localized(
+            ErrorKind::InvalidPhoneNumber,
+            "phone_number_error",
+            &[("error", &e)],
+            format_args!("not a valid phone number: {e}"),
+        )
+        .with_rule("phone_number")),
     }
 }
 
@@ -16,13 +16,18 @@
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
-use alloc::format;
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Prefix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_prefix(pat) {
-        return Err(Error::new(format!("value does not begin with \"{pat}\"")));
+        return Err(Error::localized(
+            ErrorKind::MissingPrefix,
+            "prefix",
+            &[("pattern", &pat)],
+            format_args!("value does not begin with \"{pat}\""),
+        )
+        .with_rule("prefix"));
     }
     Ok(())
 }
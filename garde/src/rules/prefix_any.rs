@@ -0,0 +1,53 @@
+//! Prefix validation against several candidates.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(prefix_any("http://", "https://"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The value is valid if it starts with _any_ of the candidates.
+//!
+//! The entrypoint is the [`PrefixAny`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(prefix_any)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::one_of::Values;
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: PrefixAny>(v: &T, (patterns,): (&[&str],)) -> Result<(), Error> {
+    if !v.validate_prefix_any(patterns) {
+        let patterns = Values(patterns);
+        return Err(Error::localized(
+            ErrorKind::MissingPrefix,
+            "prefix_any",
+            &[("patterns", &patterns)],
+            format_args!("value does not begin with any of {patterns}"),
+        )
+        .with_rule("prefix_any"));
+    }
+    Ok(())
+}
+
+pub trait PrefixAny {
+    fn validate_prefix_any(&self, patterns: &[&str]) -> bool;
+}
+
+impl<T: AsStr> PrefixAny for T {
+    fn validate_prefix_any(&self, patterns: &[&str]) -> bool {
+        let value = self.as_str();
+        patterns.iter().any(|pat| value.starts_with(pat))
+    }
+}
+
+impl<T: PrefixAny> PrefixAny for Option<T> {
+    fn validate_prefix_any(&self, patterns: &[&str]) -> bool {
+        match self {
+            Some(value) => value.validate_prefix_any(patterns),
+            None => true,
+        }
+    }
+}
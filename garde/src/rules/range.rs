@@ -10,12 +10,12 @@
 //!
 //! The entrypoint is the [`Bounds`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(range(...))]` rule.
 //!
-//! This trait is implemented for all primitive integer types.
+//! This trait is implemented for all primitive integer and floating point types.
+//! For floats, `NaN` is always out of bounds, and omitted bounds default to ±infinity.
 
-use alloc::format;
 use core::fmt::Display;
 
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 #[inline]
 pub fn apply<T: Bounds>(
@@ -26,8 +26,33 @@ pub fn apply<T: Bounds>(
     let max = max.unwrap_or(T::MAX);
     if let Err(e) = v.validate_bounds(min, max) {
         match e {
-            OutOfBounds::Lower => return Err(Error::new(format!("lower than {min}"))),
-            OutOfBounds::Upper => return Err(Error::new(format!("greater than {max}"))),
+            OutOfBounds::Lower => {
+                return Err(Error::localized(
+                    ErrorKind::OutOfRange,
+                    "range_lower_than",
+                    &[("min", &min)],
+                    format_args!("lower than {min}"),
+                )
+                .with_rule("range"))
+            }
+            OutOfBounds::Upper => {
+                return Err(Error::localized(
+                    ErrorKind::OutOfRange,
+                    "range_greater_than",
+                    &[("max", &max)],
+                    format_args!("greater than {max}"),
+                )
+                .with_rule("range"))
+            }
+            OutOfBounds::NotANumber => {
+                return Err(Error::localized(
+                    ErrorKind::OutOfRange,
+                    "range_not_a_number",
+                    &[],
+                    format_args!("not a number"),
+                )
+                .with_rule("range"))
+            }
         }
     }
     Ok(())
@@ -49,6 +74,7 @@ pub trait Bounds: PartialOrd {
 pub enum OutOfBounds {
     Lower,
     Upper,
+    NotANumber,
 }
 
 macro_rules! impl_for_int {
@@ -78,7 +104,38 @@ macro_rules! impl_for_int {
     };
 }
 
-impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64);
+impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128);
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl Bounds for $T {
+                type Size = $T;
+
+                const MIN: Self::Size = $T::NEG_INFINITY;
+                const MAX: Self::Size = $T::INFINITY;
+
+                fn validate_bounds(
+                    &self,
+                    lower_bound: Self::Size,
+                    upper_bound: Self::Size,
+                ) -> Result<(), OutOfBounds> {
+                    if self.is_nan() {
+                        Err(OutOfBounds::NotANumber)
+                    } else if self < &lower_bound {
+                        Err(OutOfBounds::Lower)
+                    } else if self > &upper_bound {
+                        Err(OutOfBounds::Upper)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
 
 impl<T: Bounds> Bounds for Option<T> {
     type Size = T::Size;
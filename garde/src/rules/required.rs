@@ -1,8 +1,28 @@
-use crate::{Error, Result};
+//! Presence validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(required)]
+//!     v: Option<String>,
+//!     #[garde(required)]
+//!     w: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Required`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(required)]` rule.
+//!
+//! An `Option` is set if it is `Some`, and a string is set if it is not empty after trimming whitespace.
+//! Note that `Some("")` is set, use `#[garde(required, inner(required))]` to also reject empty inner strings.
+
+use crate::{Error, ErrorKind, Result};
 
 pub fn apply<T: Required>(v: &T, _: ()) -> Result {
     if !v.is_set() {
-        return Err(Error::new("not set"));
+        return Err(
+            Error::localized(ErrorKind::NotSet, "required", &[], format_args!("not set"))
+                .with_rule("required"),
+        );
     }
     Ok(())
 }
@@ -16,3 +36,21 @@ impl<T> Required for Option<T> {
         self.is_some()
     }
 }
+
+macro_rules! impl_via_trim {
+    ($(in<$lifetime:lifetime>)? $T:ty) => {
+        impl<$($lifetime)?> Required for $T {
+            fn is_set(&self) -> bool {
+                !self.trim().is_empty()
+            }
+        }
+    };
+}
+
+impl_via_trim!(alloc::string::String);
+impl_via_trim!(in<'a> &'a alloc::string::String);
+impl_via_trim!(in<'a> &'a str);
+impl_via_trim!(in<'a> alloc::borrow::Cow<'a, str>);
+impl_via_trim!(alloc::rc::Rc<str>);
+impl_via_trim!(alloc::sync::Arc<str>);
+impl_via_trim!(alloc::boxed::Box<str>);
@@ -16,13 +16,18 @@
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
-use alloc::format;
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Suffix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_suffix(pat) {
-        return Err(Error::new(format!("does not end with \"{pat}\"")));
+        return Err(Error::localized(
+            ErrorKind::MissingSuffix,
+            "suffix",
+            &[("pattern", &pat)],
+            format_args!("does not end with \"{pat}\""),
+        )
+        .with_rule("suffix"));
     }
     Ok(())
 }
@@ -0,0 +1,53 @@
+//! Suffix validation against several candidates.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(suffix_any(".png", ".jpg"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The value is valid if it ends with _any_ of the candidates.
+//!
+//! The entrypoint is the [`SuffixAny`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(suffix_any)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::one_of::Values;
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: SuffixAny>(v: &T, (patterns,): (&[&str],)) -> Result<(), Error> {
+    if !v.validate_suffix_any(patterns) {
+        let patterns = Values(patterns);
+        return Err(Error::localized(
+            ErrorKind::MissingSuffix,
+            "suffix_any",
+            &[("patterns", &patterns)],
+            format_args!("value does not end with any of {patterns}"),
+        )
+        .with_rule("suffix_any"));
+    }
+    Ok(())
+}
+
+pub trait SuffixAny {
+    fn validate_suffix_any(&self, patterns: &[&str]) -> bool;
+}
+
+impl<T: AsStr> SuffixAny for T {
+    fn validate_suffix_any(&self, patterns: &[&str]) -> bool {
+        let value = self.as_str();
+        patterns.iter().any(|pat| value.ends_with(pat))
+    }
+}
+
+impl<T: SuffixAny> SuffixAny for Option<T> {
+    fn validate_suffix_any(&self, patterns: &[&str]) -> bool {
+        match self {
+            Some(value) => value.validate_suffix_any(patterns),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Text hygiene validation, for generated files and other line-oriented text.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(no_trailing_whitespace, final_newline)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! - `no_trailing_whitespace` fails if any line ends with whitespace, and reports the index of the first such line, starting at `0`.
+//!   Both `\n` and `\r\n` are accepted as line endings.
+//! - `final_newline` fails if a non-empty value does not end with `\n`.
+//!
+//! The entrypoint is the [`TextHygiene`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(no_trailing_whitespace)]` and `#[garde(final_newline)]` rules.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: TextHygiene>(v: &T, (kind,): (TextHygieneKind,)) -> Result<(), Error> {
+    match v.validate_text_hygiene(kind) {
+        Ok(()) => Ok(()),
+        Err(TextHygieneError::TrailingWhitespace { line }) => Err(Error::localized(
+            ErrorKind::TrailingWhitespace,
+            "no_trailing_whitespace",
+            &[("line", &line)],
+            format_args!("trailing whitespace at line index {line}"),
+        )
+        .with_rule(kind.rule())),
+        Err(TextHygieneError::MissingFinalNewline) => Err(Error::localized(
+            ErrorKind::MissingFinalNewline,
+            "final_newline",
+            &[],
+            format_args!("does not end with a newline"),
+        )
+        .with_rule(kind.rule())),
+    }
+}
+
+pub trait TextHygiene {
+    fn validate_text_hygiene(&self, kind: TextHygieneKind) -> Result<(), TextHygieneError>;
+}
+
+#[derive(Clone, Copy)]
+pub enum TextHygieneKind {
+    NoTrailingWhitespace,
+    FinalNewline,
+}
+
+impl TextHygieneKind {
+    /// The name of the rule which performs this check.
+    fn rule(self) -> &'static str {
+        match self {
+            TextHygieneKind::NoTrailingWhitespace => "no_trailing_whitespace",
+            TextHygieneKind::FinalNewline => "final_newline",
+        }
+    }
+}
+
+pub enum TextHygieneError {
+    /// The line at index `line` ends with whitespace.
+    TrailingWhitespace {
+        line: usize,
+    },
+    MissingFinalNewline,
+}
+
+impl<T: AsStr> TextHygiene for T {
+    fn validate_text_hygiene(&self, kind: TextHygieneKind) -> Result<(), TextHygieneError> {
+        let v = self.as_str();
+        match kind {
+            TextHygieneKind::NoTrailingWhitespace => {
+                let mut lines = v.lines();
+                match lines.position(|line| line.ends_with(char::is_whitespace)) {
+                    Some(line) => Err(TextHygieneError::TrailingWhitespace { line }),
+                    None => Ok(()),
+                }
+            }
+            TextHygieneKind::FinalNewline => {
+                if v.is_empty() || v.ends_with('\n') {
+                    Ok(())
+                } else {
+                    Err(TextHygieneError::MissingFinalNewline)
+                }
+            }
+        }
+    }
+}
+
+impl<T: TextHygiene> TextHygiene for Option<T> {
+    fn validate_text_hygiene(&self, kind: TextHygieneKind) -> Result<(), TextHygieneError> {
+        match self {
+            Some(value) => value.validate_text_hygiene(kind),
+            None => Ok(()),
+        }
+    }
+}
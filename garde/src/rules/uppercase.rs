@@ -0,0 +1,64 @@
+//! Uppercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uppercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Fails if any character is [`lowercase`](char::is_lowercase). Characters without case, such as digits or punctuation, are ignored.
+//!
+//! The entrypoint is the [`Uppercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uppercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Uppercase>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(NotUppercase { index, character }) = v.validate_uppercase() {
+        return Err(Error::localized(
+            ErrorKind::NotUppercase,
+            "uppercase",
+            &[("character", &character), ("index", &index)],
+            format_args!("not uppercase, found {character:?} at index {index}"),
+        )
+        .with_rule("uppercase"));
+    }
+    Ok(())
+}
+
+pub trait Uppercase {
+    fn validate_uppercase(&self) -> Result<(), NotUppercase>;
+}
+
+/// The first offending character, and its index in terms of `char`s.
+pub struct NotUppercase {
+    pub index: usize,
+    pub character: char,
+}
+
+impl<T: AsStr> Uppercase for T {
+    fn validate_uppercase(&self) -> Result<(), NotUppercase> {
+        match self
+            .as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| c.is_lowercase())
+        {
+            Some((index, character)) => Err(NotUppercase { index, character }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Uppercase> Uppercase for Option<T> {
+    fn validate_uppercase(&self) -> Result<(), NotUppercase> {
+        match self {
+            Some(value) => value.validate_uppercase(),
+            None => Ok(()),
+        }
+    }
+}
@@ -14,16 +14,24 @@
 //!
 //! If you need to implement this for a string-like type where a contiguous slice of the entire contents cannot be obtained,
 //! then there is currently no way for you to implement this trait.
+//!
+//! The [`url`] crate requires `std`, so enabling the `url` feature also enables `std`.
+//! This rule is unavailable in pure `no_std` builds.
 
-use alloc::format;
 use core::fmt::Display;
 
 use super::AsStr;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 
 pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(e) = v.validate_url() {
-        return Err(Error::new(format!("not a valid url: {e}")));
+        return Err(Error::localized(
+            ErrorKind::InvalidUrl,
+            "url",
+            &[("error", &e)],
+            format_args!("not a valid url: {e}"),
+        )
+        .with_rule("url"));
     }
     Ok(())
 }
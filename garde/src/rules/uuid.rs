@@ -0,0 +1,97 @@
+//! UUID string validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uuid)]
+//!     id: String,
+//!     #[garde(uuid_any)]
+//!     external_id: String,
+//! }
+//! ```
+//!
+//! - `uuid` accepts the canonical hyphenated form, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+//! - `uuid_any` additionally accepts the braced form (`{67e55044-...}`) and the URN form (`urn:uuid:67e55044-...`).
+//!
+//! Hex digits may be upper or lower case. Only the format is checked, not the version or variant bits.
+//!
+//! The entrypoint is the [`Uuid`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uuid)]` and `#[garde(uuid_any)]` rules.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::{Error, ErrorKind};
+
+pub fn apply<T: Uuid>(v: &T, (format,): (UuidFormat,)) -> Result<(), Error> {
+    if !v.validate_uuid(format) {
+        return Err(Error::localized(
+            ErrorKind::InvalidUuid,
+            "uuid",
+            &[],
+            format_args!("not a valid UUID"),
+        )
+        .with_rule(format.rule()));
+    }
+    Ok(())
+}
+
+pub trait Uuid {
+    fn validate_uuid(&self, format: UuidFormat) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum UuidFormat {
+    /// Only the hyphenated form.
+    Hyphenated,
+    /// The hyphenated, braced or URN form.
+    Any,
+}
+
+impl UuidFormat {
+    /// The name of the rule which checks for this format.
+    fn rule(self) -> &'static str {
+        match self {
+            UuidFormat::Hyphenated => "uuid",
+            UuidFormat::Any => "uuid_any",
+        }
+    }
+}
+
+impl<T: AsStr> Uuid for T {
+    fn validate_uuid(&self, format: UuidFormat) -> bool {
+        let v = self.as_str();
+        match format {
+            UuidFormat::Hyphenated => is_hyphenated(v.as_bytes()),
+            UuidFormat::Any => {
+                let v = v.as_bytes();
+                let inner =
+                    if let Some(inner) = v.strip_prefix(b"{").and_then(|v| v.strip_suffix(b"}")) {
+                        inner
+                    } else if v.len() > 9 && v[..9].eq_ignore_ascii_case(b"urn:uuid:") {
+                        &v[9..]
+                    } else {
+                        v
+                    };
+                is_hyphenated(inner)
+            }
+        }
+    }
+}
+
+impl<T: Uuid> Uuid for Option<T> {
+    fn validate_uuid(&self, format: UuidFormat) -> bool {
+        match self {
+            Some(value) => value.validate_uuid(format),
+            None => true,
+        }
+    }
+}
+
+/// `8-4-4-4-12` hex digits.
+fn is_hyphenated(v: &[u8]) -> bool {
+    v.len() == 36
+        && v.iter().enumerate().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => *c == b'-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
@@ -3,7 +3,7 @@
 use alloc::borrow::ToOwned;
 use core::fmt::Debug;
 
-use crate::error::{Path, PathComponentKind};
+use crate::error::{Error, Path, PathComponentKind};
 use crate::Report;
 
 /// The core trait of this crate.
@@ -35,14 +35,48 @@ pub trait Validate {
     /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
     /// because [`Validate::validate_with`] has a default implementation that calls [`Validate::validate_into`].
     fn validate_with(&self, ctx: &Self::Context) -> Result<(), Report> {
-        let mut report = Report::new();
+        let mut report = Report::with_capacity(self.report_capacity());
         self.validate_into(ctx, &mut Path::empty, &mut report);
-        match report.is_empty() {
-            true => Ok(()),
-            false => Err(report),
+        report.into_result()
+    }
+
+    /// The number of errors to allocate room for in the [`Report`] created by [`Validate::validate_with`].
+    ///
+    /// Derived implementations return the number of validated fields. The default of `0` allocates lazily.
+    fn report_capacity(&self) -> usize {
+        0
+    }
+
+    /// Validates `Self`, returning only the first error if the validation failed.
+    ///
+    /// Derived implementations stop evaluating rules after the first failure,
+    /// so this is cheaper than [`Validate::validate`] when the full report is not needed.
+    fn validate_first_error(&self) -> Result<(), Error>
+    where
+        Self::Context: Default,
+    {
+        let ctx = Self::Context::default();
+        self.validate_first_error_with(&ctx)
+    }
+
+    /// Same as [`Validate::validate_first_error`], with a user-provided context.
+    fn validate_first_error_with(&self, ctx: &Self::Context) -> Result<(), Error> {
+        let mut report = Report::first_only();
+        self.validate_into(ctx, &mut Path::empty, &mut report);
+        match report.into_inner().pop() {
+            Some((_, error)) => Err(error),
+            None => Ok(()),
         }
     }
 
+    /// Returns `true` if `Self` passes validation, stopping at the first failure.
+    fn is_valid(&self) -> bool
+    where
+        Self::Context: Default,
+    {
+        self.validate_first_error().is_ok()
+    }
+
     /// Validates `Self`, aggregating all validation errors into `Report`.
     fn validate_into(
         &self,
@@ -52,6 +86,65 @@ pub trait Validate {
     );
 }
 
+/// Validates every item of `iter`, lazily yielding the index and [`Report`]
+/// of each item which failed validation.
+///
+/// Valid items are skipped, and each report is dropped as soon as the caller is done with it,
+/// so this can be used on large streams of values without collecting every error at once.
+///
+/// ```rust
+/// #[derive(garde::Validate)]
+/// struct Record<'a> {
+///     #[garde(char_length(min = 1))]
+///     name: &'a str,
+/// }
+///
+/// let records = [Record { name: "a" }, Record { name: "" }, Record { name: "b" }];
+/// let failed: Vec<usize> = garde::validate_iter(&records).map(|(index, _)| index).collect();
+/// assert_eq!(failed, [1]);
+/// ```
+pub fn validate_iter<I>(iter: I) -> impl Iterator<Item = (usize, Report)>
+where
+    I: IntoIterator,
+    I::Item: Validate,
+    <I::Item as Validate>::Context: Default,
+{
+    let ctx = <I::Item as Validate>::Context::default();
+    iter.into_iter()
+        .enumerate()
+        .filter_map(move |(index, item)| match item.validate_with(&ctx) {
+            Ok(()) => None,
+            Err(report) => Some((index, report)),
+        })
+}
+
+/// Validates `value`, returning it unchanged along with its [`Report`].
+///
+/// Unlike [`Validate::validate`], the value is kept whether or not it is valid, and the report
+/// also holds any warnings. Use [`Report::is_valid`] to check whether validation passed.
+///
+/// ```rust
+/// #[derive(garde::Validate)]
+/// struct Record<'a> {
+///     #[garde(char_length(min = 1))]
+///     name: &'a str,
+/// }
+///
+/// let (record, report) = garde::validate_and_collect(Record { name: "" });
+/// assert_eq!(record.name, "");
+/// assert!(!report.is_valid());
+/// ```
+pub fn validate_and_collect<T>(value: T) -> (T, Report)
+where
+    T: Validate,
+    T::Context: Default,
+{
+    let ctx = T::Context::default();
+    let mut report = Report::with_capacity(value.report_capacity());
+    value.validate_into(&ctx, &mut Path::empty, &mut report);
+    (value, report)
+}
+
 /// A struct which wraps a valid instance of some `T`.
 ///
 /// The only way to create an instance of this struct is through the `validate`
@@ -121,7 +214,7 @@ impl<T: Debug> Debug for Unvalidated<T> {
     }
 }
 
-impl<'a, T: ?Sized + Validate> Validate for &'a T {
+impl<T: ?Sized + Validate> Validate for &T {
     type Context = T::Context;
 
     fn validate_into(
@@ -132,9 +225,13 @@ impl<'a, T: ?Sized + Validate> Validate for &'a T {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn report_capacity(&self) -> usize {
+        <T as Validate>::report_capacity(self)
+    }
 }
 
-impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
+impl<T: ?Sized + Validate> Validate for &mut T {
     type Context = T::Context;
 
     fn validate_into(
@@ -145,9 +242,13 @@ impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn report_capacity(&self) -> usize {
+        <T as Validate>::report_capacity(self)
+    }
 }
 
-impl<T: Validate> Validate for alloc::boxed::Box<T> {
+impl<T: ?Sized + Validate> Validate for alloc::boxed::Box<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -158,9 +259,13 @@ impl<T: Validate> Validate for alloc::boxed::Box<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn report_capacity(&self) -> usize {
+        <T as Validate>::report_capacity(self)
+    }
 }
 
-impl<T: Validate> Validate for alloc::rc::Rc<T> {
+impl<T: ?Sized + Validate> Validate for alloc::rc::Rc<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -171,9 +276,13 @@ impl<T: Validate> Validate for alloc::rc::Rc<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn report_capacity(&self) -> usize {
+        <T as Validate>::report_capacity(self)
+    }
 }
 
-impl<T: Validate> Validate for alloc::sync::Arc<T> {
+impl<T: ?Sized + Validate> Validate for alloc::sync::Arc<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -184,6 +293,10 @@ impl<T: Validate> Validate for alloc::sync::Arc<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn report_capacity(&self) -> usize {
+        <T as Validate>::report_capacity(self)
+    }
 }
 
 macro_rules! impl_validate_list {
@@ -331,6 +444,10 @@ impl<T: Validate> Validate for Option<T> {
             value.validate_into(ctx, parent, report)
         }
     }
+
+    fn report_capacity(&self) -> usize {
+        self.as_ref().map_or(0, T::report_capacity)
+    }
 }
 
 impl<'a, B: Validate> Validate for alloc::borrow::Cow<'a, B>
@@ -347,4 +464,8 @@ where
     ) {
         self.as_ref().validate_into(ctx, parent, report)
     }
+
+    fn report_capacity(&self) -> usize {
+        self.as_ref().report_capacity()
+    }
 }
@@ -0,0 +1,58 @@
+//! Installing a message provider affects the whole process, so this lives in its own test binary.
+
+use garde::error::messages::{set_message_provider, Message, MessageProvider};
+use garde::external::compact_str::{format_compact, CompactString};
+use garde::{ErrorKind, Validate};
+
+struct French;
+
+impl MessageProvider for French {
+    fn message(&self, message: &Message<'_>) -> Option<CompactString> {
+        match (message.kind(), message.id()) {
+            (ErrorKind::BytesOutOfRange | ErrorKind::CharsOutOfRange, "length_greater_than") => {
+                Some(format_compact!(
+                    "la longueur est supérieure à {}",
+                    message.param("max")?
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(byte_length(max = 3))]
+    name: &'a str,
+    #[garde(ascii)]
+    ascii: &'a str,
+    #[garde(length(chars, max = 3))]
+    far_over: &'a str,
+}
+
+#[test]
+fn custom_message_provider() {
+    assert!(set_message_provider(&French).is_ok());
+    assert!(set_message_provider(&French).is_err());
+
+    let report = Test {
+        name: "abcd",
+        ascii: "😂",
+        far_over: "abcdefgh",
+    }
+    .validate()
+    .unwrap_err();
+    let mut errors: Vec<String> = report
+        .iter()
+        .map(|(path, error)| format!("{path}: {error}"))
+        .collect();
+    errors.sort();
+    assert_eq!(
+        errors,
+        [
+            "ascii: not ascii, found '😂' at index 0",
+            "far_over: la longueur est supérieure à 3",
+            "name: la longueur est supérieure à 3",
+        ]
+    );
+}
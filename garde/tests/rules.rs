@@ -1,2 +1,5 @@
+// bare `length` on strings is deprecated, but many of these tests still rely on it
+#![allow(deprecated)]
+
 #[path = "./rules/mod.rs"]
 mod rules;
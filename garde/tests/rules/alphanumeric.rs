@@ -30,3 +30,45 @@ fn alphanumeric_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Handle<'a> {
+    #[garde(alphanumeric)]
+    handle: &'a str,
+    #[garde(alphanumeric)]
+    optional: Option<&'a str>,
+}
+
+#[test]
+fn alphanumeric_handle_valid() {
+    util::check_ok(
+        &[
+            Handle {
+                handle: "abc123ß",
+                optional: None,
+            },
+            Handle {
+                handle: "",
+                optional: Some("abc123ß"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn alphanumeric_handle_invalid() {
+    util::check_fail!(
+        &[
+            Handle {
+                handle: "user name",
+                optional: Some("usér"),
+            },
+            Handle {
+                handle: "user_😂",
+                optional: Some("ü"),
+            },
+        ],
+        &()
+    )
+}
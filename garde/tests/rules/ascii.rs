@@ -37,3 +37,45 @@ fn ascii_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Handle<'a> {
+    #[garde(ascii)]
+    handle: &'a str,
+    #[garde(ascii)]
+    optional: Option<&'a str>,
+}
+
+#[test]
+fn ascii_handle_valid() {
+    util::check_ok(
+        &[
+            Handle {
+                handle: "abc 123!",
+                optional: None,
+            },
+            Handle {
+                handle: "",
+                optional: Some("abc 123!"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn ascii_handle_invalid() {
+    util::check_fail!(
+        &[
+            Handle {
+                handle: "user name",
+                optional: Some("usér"),
+            },
+            Handle {
+                handle: "user_😂",
+                optional: Some("ü"),
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,46 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii_alphanumeric)]
+    field: &'a str,
+
+    #[garde(inner(ascii_alphanumeric))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn ascii_alphanumeric_valid() {
+    util::check_ok(
+        &[Test {
+            field: "abc123",
+            inner: &["ABCxyz0189"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn ascii_alphanumeric_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "abc١٢٣",
+            inner: &["straße"],
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Unicode<'a> {
+    #[garde(alphanumeric)]
+    field: &'a str,
+}
+
+#[test]
+fn arabic_digits_are_unicode_alphanumeric() {
+    util::check_ok(
+        &[Unicode { field: "abc123" }, Unicode { field: "abc١٢٣" }],
+        &(),
+    )
+}
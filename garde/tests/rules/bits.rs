@@ -0,0 +1,42 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(bits(max = 12))]
+    field: u16,
+    #[garde(bits(max = 12))]
+    optional: Option<u64>,
+    #[garde(bits(max = 8))]
+    full_width: u8,
+}
+
+#[test]
+fn bits_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: 0,
+                optional: None,
+                full_width: 0,
+            },
+            Test {
+                field: 4095,
+                optional: Some(4095),
+                full_width: u8::MAX,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn bits_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: 4096,
+            optional: Some(u64::MAX),
+            full_width: 0,
+        }],
+        &()
+    )
+}
@@ -0,0 +1,54 @@
+use garde::bounded::{BoundedString, BoundedVec};
+use garde::Validate;
+
+#[test]
+fn bounded_string() {
+    assert!(BoundedString::<1, 10>::new("a".into()).validate().is_ok());
+    assert!(BoundedString::<1, 10>::new("0123456789".into())
+        .validate()
+        .is_ok());
+
+    let report = BoundedString::<1, 10>::new(String::new())
+        .validate()
+        .unwrap_err();
+    assert_eq!(report.to_string(), "length 0 is lower than minimum 1\n");
+
+    let report = BoundedString::<1, 10>::new("01234567890".into())
+        .validate()
+        .unwrap_err();
+    assert_eq!(report.to_string(), "length 11 is greater than maximum 10\n");
+}
+
+#[test]
+fn bounded_vec() {
+    assert!(BoundedVec::<u8, 2, 3>::new(vec![1, 2]).validate().is_ok());
+    assert!(BoundedVec::<u8, 2, 3>::new(vec![1]).validate().is_err());
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(dive)]
+    name: BoundedString<1, 10>,
+    #[garde(dive)]
+    tags: BoundedVec<String, 0, 2>,
+}
+
+#[test]
+fn bounded_field_path() {
+    let value = Test {
+        name: String::new().into(),
+        tags: vec!["a".into(), "b".into(), "c".into()].into(),
+    };
+    let report = value.validate().unwrap_err();
+    let errors: Vec<_> = report
+        .iter()
+        .map(|(path, error)| format!("{path}: {error}"))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            "name: length 0 is lower than minimum 1",
+            "tags: length 3 is greater than maximum 2",
+        ]
+    );
+}
@@ -0,0 +1,64 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(char_range(min = 'A', max = 'F'))]
+    grade: char,
+    #[garde(char_range(max = 'z'))]
+    optional: Option<char>,
+}
+
+#[test]
+fn char_range_valid() {
+    util::check_ok(
+        &[
+            Test {
+                grade: 'A',
+                optional: None,
+            },
+            Test {
+                grade: 'F',
+                optional: Some('z'),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn char_range_below_invalid() {
+    util::check_fail!(
+        &[Test {
+            grade: '@',
+            optional: None,
+        }],
+        &()
+    )
+}
+
+#[test]
+fn char_range_above_invalid() {
+    util::check_fail!(
+        &[Test {
+            grade: 'G',
+            optional: Some('{'),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Exact {
+    #[garde(char_range(equal = 'x', code = "not_x"))]
+    v: char,
+}
+
+#[test]
+fn char_range_exact() {
+    util::check_ok(&[Exact { v: 'x' }], &());
+
+    let error = garde::Validate::validate(&Exact { v: 'y' }).unwrap_err();
+    let (_, error) = error.iter().next().unwrap();
+    assert_eq!(error.kind(), garde::ErrorKind::CharOutOfRange);
+    assert_eq!(error.code(), Some("not_x"));
+}
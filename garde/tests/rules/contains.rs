@@ -42,3 +42,39 @@ fn contains_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct CaseSensitive<'a> {
+    #[garde(contains("@"))]
+    handle: &'a str,
+    #[garde(contains("Tag"))]
+    tag: Option<&'a str>,
+}
+
+#[test]
+fn contains_case_sensitive_valid() {
+    util::check_ok(
+        &[
+            CaseSensitive {
+                handle: "@user",
+                tag: Some("MyTag"),
+            },
+            CaseSensitive {
+                handle: "user@host",
+                tag: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn contains_case_sensitive_invalid() {
+    util::check_fail!(
+        &[CaseSensitive {
+            handle: "user",
+            tag: Some("MYTAG"),
+        }],
+        &()
+    )
+}
@@ -42,3 +42,52 @@ fn credit_card_invalid() {
         &()
     )
 }
+
+#[test]
+fn credit_card_formatted_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "4111 1111 1111 1111",
+                inner: &["4111-1111-1111-1111"],
+            },
+            Test {
+                field: "4111111111111111",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn credit_card_formatted_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                // fails luhn
+                field: "4111 1111 1111 1112",
+                inner: &["4111-1111-1111-1112"],
+            },
+            Test {
+                field: "4111_1111_1111_1111",
+                inner: &["4111.1111.1111.1111"],
+            },
+            Test {
+                field: "4111 1111 111",
+                inner: &["4111 1111 1111 1111 1111"],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn credit_card_long_input() {
+    let digits = "9".repeat(1000);
+    let error = garde::rules::credit_card::apply(&digits.as_str(), ()).unwrap_err();
+    assert_eq!(
+        error.message(),
+        "not a valid credit card number: invalid length"
+    );
+}
@@ -108,3 +108,69 @@ fn multi_custom_invalid() {
         &ctx
     )
 }
+
+struct Limits {
+    max_quantity: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+struct Order {
+    #[garde(custom(below_max_quantity))]
+    quantity: u32,
+}
+
+fn below_max_quantity(value: &u32, ctx: &Limits) -> garde::Result {
+    if *value > ctx.max_quantity {
+        return Err(garde::Error::new(format!(
+            "greater than the limit of {}",
+            ctx.max_quantity
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn custom_threshold_from_context_valid() {
+    let ctx = Limits { max_quantity: 10 };
+    util::check_ok(&[Order { quantity: 0 }, Order { quantity: 10 }], &ctx)
+}
+
+#[test]
+fn custom_threshold_from_context_invalid() {
+    let ctx = Limits { max_quantity: 10 };
+    util::check_fail!(&[Order { quantity: 11 }], &ctx)
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+struct Cart {
+    #[garde(dive)]
+    orders: Vec<Order>,
+}
+
+#[test]
+fn custom_context_through_dive() {
+    use garde::Validate as _;
+
+    let cart = Cart {
+        orders: vec![Order { quantity: 5 }, Order { quantity: 20 }],
+    };
+
+    assert!(cart.validate_with(&Limits { max_quantity: 20 }).is_ok());
+
+    let report = cart
+        .validate_with(&Limits { max_quantity: 10 })
+        .unwrap_err();
+    let errors: Vec<_> = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.message().to_string()))
+        .collect();
+    assert_eq!(
+        errors,
+        [(
+            "orders[1].quantity".to_string(),
+            "greater than the limit of 10".to_string()
+        )]
+    );
+}
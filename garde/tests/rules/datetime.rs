@@ -0,0 +1,59 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(datetime)]
+    field: &'a str,
+    #[garde(inner(datetime))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn datetime_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "2023-01-02T03:04:05Z",
+                inner: &["2023-01-02"],
+            },
+            Test {
+                field: "1985-04-12T23:20:50.52+05:30",
+                inner: &["1990-12-31 23:59:60-08:00"],
+            },
+            Test {
+                field: "2023-01-02t03:04:05",
+                inner: &["0000-01-01T00:00:00.000000z"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn datetime_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "2023-13-01",
+                inner: &["not-a-date"],
+            },
+            Test {
+                field: "2023-01-00",
+                inner: &["2023-1-02"],
+            },
+            Test {
+                field: "2023-01-02T24:00:00Z",
+                inner: &["2023-01-02T03:04"],
+            },
+            Test {
+                field: "2023-01-02T03:04:05.Z",
+                inner: &["2023-01-02T03:04:05+0530"],
+            },
+            Test {
+                field: "2023-01-02Z",
+                inner: &["2023-01-02T03:04:05Z "],
+            },
+        ],
+        &()
+    )
+}
@@ -1,9 +1,11 @@
 use std::rc::Rc;
 use std::sync::Arc;
 
+use garde::Validate;
+
 use super::util;
 
-#[derive(Clone, Copy, Debug, garde::Validate)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, garde::Validate)]
 struct Inner<'a> {
     #[garde(length(min = 1))]
     field: &'a str,
@@ -68,3 +70,116 @@ fn email_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Collections<'a> {
+    #[garde(dive)]
+    vec: Vec<Inner<'a>>,
+    #[garde(dive)]
+    map: std::collections::BTreeMap<&'a str, Inner<'a>>,
+}
+
+#[test]
+fn dive_collections_mixed_invalid() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    util::check_fail!(
+        &[Collections {
+            vec: vec![valid, invalid, valid, invalid],
+            map: [("a", valid), ("b", invalid)].into_iter().collect(),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn collections_validate_elements() {
+    use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+    fn paths<T: garde::Validate<Context = ()>>(value: T) -> Vec<String> {
+        match value.validate() {
+            Ok(()) => vec![],
+            Err(report) => report.iter().map(|(path, _)| path.to_string()).collect(),
+        }
+    }
+
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+
+    assert_eq!(paths(vec![valid, invalid, valid]), ["[1].field"]);
+    assert_eq!(
+        paths(BTreeMap::from([
+            ("a".to_string(), invalid),
+            ("b".to_string(), valid)
+        ])),
+        ["a.field"]
+    );
+    assert_eq!(paths(VecDeque::from([invalid, valid])), ["[0].field"]);
+    assert_eq!(paths(BTreeSet::from([valid, invalid])), ["[0].field"]);
+    assert!(paths(vec![valid, valid]).is_empty());
+}
+
+#[test]
+fn tuple_validate_elements() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+
+    assert!((valid, valid).validate().is_ok());
+
+    let report = (valid, invalid).validate().unwrap_err();
+    let paths: Vec<_> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["[1].field"]);
+}
+
+#[derive(Debug, garde::Validate)]
+struct Wrappers<'a> {
+    #[garde(dive)]
+    boxed: Box<Inner<'a>>,
+    #[garde(dive)]
+    optional: Option<Inner<'a>>,
+    #[garde(dive)]
+    boxed_slice: Box<[Inner<'a>]>,
+    #[garde(dive)]
+    shared: Arc<[Inner<'a>]>,
+}
+
+#[test]
+fn dive_wrappers_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[Wrappers {
+            boxed: Box::new(inner),
+            optional: None,
+            boxed_slice: Box::new([]),
+            shared: Arc::new([inner]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn dive_wrappers_invalid() {
+    let inner = Inner { field: "" };
+    util::check_fail!(
+        &[Wrappers {
+            boxed: Box::new(inner),
+            optional: Some(inner),
+            boxed_slice: Box::new([inner]),
+            shared: Arc::new([inner]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn validate_through_reference() {
+    use garde::Validate;
+
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+
+    assert!(<&Inner as Validate>::validate(&&valid).is_ok());
+    assert!(<&&Inner as Validate>::validate(&&&valid).is_ok());
+    let report = <&Inner as Validate>::validate(&&invalid).unwrap_err();
+    assert_eq!(report.iter().next().unwrap().0.to_string(), "field");
+}
@@ -0,0 +1,64 @@
+use std::cell::Cell;
+
+use garde::Validate;
+
+#[derive(Default)]
+struct Calls(Cell<usize>);
+
+fn count(_: &str, calls: &Calls) -> garde::Result {
+    calls.0.set(calls.0.get() + 1);
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Calls))]
+struct Inner<'a> {
+    #[garde(custom(count))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Calls))]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    first: &'a str,
+    #[garde(custom(count), length(min = 1))]
+    second: &'a str,
+    #[garde(dive)]
+    inner: Vec<Inner<'a>>,
+}
+
+#[test]
+fn first_error_stops_at_first_failure() {
+    let value = Test {
+        first: "",
+        second: "",
+        inner: vec![Inner { field: "a" }, Inner { field: "b" }],
+    };
+
+    let calls = Calls::default();
+    let error = value.validate_first_error_with(&calls).unwrap_err();
+    assert_eq!(error.message(), "length 0 is lower than minimum 1");
+    assert_eq!(calls.0.get(), 0);
+    assert!(!value.is_valid());
+
+    // the full report still evaluates every rule
+    let calls = Calls::default();
+    let report = value.validate_with(&calls).unwrap_err();
+    assert_eq!(report.len(), 2);
+    assert_eq!(calls.0.get(), 3);
+}
+
+#[test]
+fn first_error_valid() {
+    let value = Test {
+        first: "a",
+        second: "b",
+        inner: vec![Inner { field: "c" }],
+    };
+
+    let calls = Calls::default();
+    assert!(value.validate_first_error_with(&calls).is_ok());
+    assert_eq!(calls.0.get(), 2);
+    assert!(value.is_valid());
+}
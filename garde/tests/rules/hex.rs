@@ -0,0 +1,49 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(hex)]
+    hex: &'a str,
+    #[garde(hex_color)]
+    color: &'a str,
+    #[garde(inner(hex_color))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn hex_valid() {
+    util::check_ok(
+        &[
+            Test {
+                hex: "deadbeef",
+                color: "#fff",
+                inner: &["#FF00aa", "ff00aa80"],
+            },
+            Test {
+                hex: "",
+                color: "abc",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn hex_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                hex: "xyz",
+                color: "#ff",
+                inner: &["#ggg", "##fff"],
+            },
+            Test {
+                hex: "abc",
+                color: "#fffff",
+                inner: &["#"],
+            },
+        ],
+        &()
+    )
+}
@@ -119,3 +119,55 @@ fn alphanumeric_none_valid() {
         &(),
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Tags {
+    #[garde(inner(length(chars, max = 20)))]
+    tags: Vec<String>,
+}
+
+#[test]
+fn inner_length_only_reports_failing_index() {
+    let tags = Tags {
+        tags: vec!["rust".into(), "a".repeat(21), "no_std".into()],
+    };
+    let report = garde::Validate::validate(&tags).unwrap_err();
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["tags[1]"]);
+}
+
+#[derive(Debug, garde::Validate)]
+struct Labels {
+    #[garde(inner_keys(ascii), inner_values(length(max = 4)))]
+    labels: std::collections::BTreeMap<String, String>,
+}
+
+#[test]
+fn inner_values_valid() {
+    util::check_ok(
+        &[Labels {
+            labels: [("os".into(), "bsd".into())].into(),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn inner_values_invalid() {
+    util::check_fail!(
+        &[Labels {
+            labels: [("os".into(), "linux".into()), ("ärch".into(), "x86".into()),].into(),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn inner_values_reported_at_key() {
+    let labels = Labels {
+        labels: [("os".into(), "linux".into()), ("arch".into(), "x86".into())].into(),
+    };
+    let report = garde::Validate::validate(&labels).unwrap_err();
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["labels.os"]);
+}
@@ -229,3 +229,14 @@ fn ip_v6_invalid() {
         &()
     )
 }
+
+#[test]
+fn ip_v4_rejects_v6() {
+    util::check_fail!(
+        &[TestIpV4 {
+            field: "fe80::223:6cff:fe8a:2e8a",
+            inner: &["::1"]
+        }],
+        &()
+    )
+}
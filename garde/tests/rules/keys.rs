@@ -0,0 +1,77 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Value<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(dive, keys(length(max = 4)))]
+    map: BTreeMap<String, Value<'a>>,
+}
+
+#[test]
+fn keys_valid() {
+    util::check_ok(
+        &[Test {
+            map: BTreeMap::from([("a".into(), Value { field: "x" })]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn keys_invalid() {
+    util::check_fail!(
+        &[Test {
+            map: BTreeMap::from([
+                ("too long".into(), Value { field: "x" }),
+                ("ok".into(), Value { field: "" }),
+            ]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn keys_and_values_at_separate_paths() {
+    let value = Test {
+        map: BTreeMap::from([("too long".into(), Value { field: "" })]),
+    };
+    let report = garde::Validate::validate(&value).unwrap_err();
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["map.too long.field", "map.too long.key"]);
+}
+
+#[derive(Debug, garde::Validate)]
+struct HashMapKeys {
+    #[garde(keys(ascii, length(min = 1)))]
+    map: Option<HashMap<String, u32>>,
+}
+
+#[test]
+fn hash_map_keys_valid() {
+    util::check_ok(
+        &[
+            HashMapKeys { map: None },
+            HashMapKeys {
+                map: Some(HashMap::from([("a".into(), 0)])),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn hash_map_keys_invalid() {
+    util::check_fail!(
+        &[HashMapKeys {
+            map: Some(HashMap::from([("".into(), 0)])),
+        }],
+        &()
+    )
+}
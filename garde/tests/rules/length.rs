@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use super::util;
 
 #[derive(Debug, garde::Validate)]
@@ -174,3 +176,1492 @@ fn char_length_invalid() {
         &()
     )
 }
+
+// Fixed hasher keys, so that the snapshot has a stable iteration order.
+#[cfg(feature = "hashbrown")]
+type Hasher = std::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+
+#[cfg(feature = "hashbrown")]
+#[derive(Debug, garde::Validate)]
+struct HashMapTest {
+    #[garde(length(min = 1, max = 2))]
+    map: hashbrown::HashMap<u32, u32, Hasher>,
+    #[garde(length(min = 1, max = 2))]
+    set: hashbrown::HashSet<u32, Hasher>,
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn hashmap_length_valid() {
+    util::check_ok(
+        &[HashMapTest {
+            map: [(0, 0), (1, 1)].into_iter().collect(),
+            set: [0].into_iter().collect(),
+        }],
+        &(),
+    )
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn hashmap_length_invalid() {
+    util::check_fail!(
+        &[HashMapTest {
+            map: hashbrown::HashMap::default(),
+            set: [0, 1, 2].into_iter().collect(),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct GraphemesTest<'a> {
+    #[garde(length(graphemes, min = 1, max = 1))]
+    v: &'a str,
+}
+
+#[test]
+fn graphemes_length_valid() {
+    util::check_ok(
+        &[
+            GraphemesTest { v: "a" },
+            // family emoji: 7 chars joined by ZWJ
+            GraphemesTest {
+                v: "👨\u{200d}👩\u{200d}👧\u{200d}👦",
+            },
+            // 'e' + combining acute accent
+            GraphemesTest { v: "e\u{301}" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn graphemes_length_invalid() {
+    util::check_fail!(
+        &[
+            GraphemesTest { v: "" },
+            GraphemesTest {
+                v: "e\u{301}e\u{301}"
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Utf16Test<'a> {
+    #[garde(length(utf16, min = 2, max = 4))]
+    v: &'a str,
+}
+
+#[test]
+fn utf16_length_valid() {
+    util::check_ok(
+        &[
+            Utf16Test { v: "ab" },
+            // U+1D11E is outside the BMP, and is encoded as a surrogate pair
+            Utf16Test { v: "𝄞" },
+            Utf16Test { v: "𝄞𝄞" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn utf16_length_invalid() {
+    util::check_fail!(&[Utf16Test { v: "a" }, Utf16Test { v: "𝄞𝄞a" }], &())
+}
+
+#[derive(Debug, garde::Validate)]
+struct WordsTest<'a> {
+    #[garde(length(words, min = 1, max = 3))]
+    v: &'a str,
+}
+
+#[test]
+fn words_length_valid() {
+    util::check_ok(
+        &[
+            WordsTest { v: "one" },
+            WordsTest {
+                v: "  leading and trailing  ",
+            },
+            WordsTest {
+                v: "multiple   \t spaces\n",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn words_length_invalid() {
+    util::check_fail!(
+        &[
+            WordsTest { v: "" },
+            WordsTest { v: " \t\n " },
+            WordsTest {
+                v: "one two three four",
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct LinesTest<'a> {
+    #[garde(length(lines, min = 1, max = 2))]
+    v: &'a str,
+}
+
+#[test]
+fn lines_length_valid() {
+    util::check_ok(
+        &[
+            // single line without a newline
+            LinesTest { v: "one" },
+            // trailing newline does not add an empty line
+            LinesTest { v: "one\ntwo\n" },
+            LinesTest { v: "one\r\ntwo" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn lines_length_invalid() {
+    util::check_fail!(
+        &[
+            // empty string has no lines
+            LinesTest { v: "" },
+            LinesTest {
+                v: "one\ntwo\nthree",
+            },
+        ],
+        &()
+    )
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, garde::Validate)]
+struct PathTest<'a> {
+    #[garde(length(min = 1, max = 10))]
+    path: &'a std::path::Path,
+    #[garde(length(bytes, min = 1, max = 10))]
+    path_buf: std::path::PathBuf,
+    #[garde(length(min = 1, max = 10))]
+    os_str: &'a std::ffi::OsStr,
+    #[garde(length(bytes, min = 1, max = 10))]
+    os_string: std::ffi::OsString,
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn path_length_valid() {
+    util::check_ok(
+        &[PathTest {
+            path: std::path::Path::new("a/b.txt"),
+            path_buf: "/etc/hosts".into(),
+            os_str: std::ffi::OsStr::new("a"),
+            os_string: "0123456789".into(),
+        }],
+        &(),
+    )
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn path_length_invalid() {
+    util::check_fail!(
+        &[PathTest {
+            path: std::path::Path::new(""),
+            path_buf: "/etc/hosts.allow".into(),
+            os_str: std::ffi::OsStr::new(""),
+            os_string: "01234567890".into(),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct CStrTest<'a> {
+    #[garde(length(bytes, equal = 5))]
+    c_string: std::ffi::CString,
+    #[garde(length(bytes, equal = 5))]
+    c_str: &'a std::ffi::CStr,
+}
+
+#[test]
+fn c_str_length_valid() {
+    util::check_ok(
+        &[CStrTest {
+            c_string: std::ffi::CString::new("hello").unwrap(),
+            c_str: c"hello",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn c_str_length_invalid() {
+    util::check_fail!(
+        &[CStrTest {
+            c_string: std::ffi::CString::new("hell").unwrap(),
+            c_str: c"hello!",
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct BoxedSliceTest {
+    #[garde(length(min = 1, max = 5))]
+    boxed: Box<[u32]>,
+    #[garde(length(min = 1, max = 5))]
+    rc: std::rc::Rc<[u32]>,
+    #[garde(length(min = 1, max = 5))]
+    arc: std::sync::Arc<[u32]>,
+}
+
+#[test]
+fn boxed_slice_length_valid() {
+    util::check_ok(
+        &[BoxedSliceTest {
+            boxed: vec![1].into(),
+            rc: vec![1, 2, 3].into(),
+            arc: vec![1, 2, 3].into(),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn boxed_slice_length_invalid() {
+    util::check_fail!(
+        &[BoxedSliceTest {
+            boxed: vec![].into(),
+            rc: vec![1, 2, 3, 4, 5, 6].into(),
+            arc: vec![].into(),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct SmallVecTest {
+    #[garde(length(min = 1, max = 5))]
+    simple: garde::external::smallvec::SmallVec<u8, 4>,
+    #[garde(length(bytes, min = 1, max = 5))]
+    bytes: garde::external::smallvec::SmallVec<u8, 4>,
+    #[garde(length(chars, min = 1, max = 5))]
+    chars: garde::external::smallvec::SmallVec<char, 4>,
+}
+
+#[test]
+fn smallvec_length_valid() {
+    util::check_ok(
+        &[
+            // inline
+            SmallVecTest {
+                simple: [0, 1].into_iter().collect(),
+                bytes: [0, 1].into_iter().collect(),
+                chars: ['a', 'b'].into_iter().collect(),
+            },
+            // spilled onto the heap
+            SmallVecTest {
+                simple: [0, 1, 2, 3, 4].into_iter().collect(),
+                bytes: [0, 1, 2, 3, 4].into_iter().collect(),
+                chars: ['a', 'b', 'c', 'd', 'e'].into_iter().collect(),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn smallvec_length_invalid() {
+    use garde::external::smallvec::SmallVec;
+
+    util::check_fail!(
+        &[
+            // inline
+            SmallVecTest {
+                simple: SmallVec::new(),
+                bytes: SmallVec::new(),
+                chars: SmallVec::new(),
+            },
+            // spilled onto the heap
+            SmallVecTest {
+                simple: [0, 1, 2, 3, 4, 5].into_iter().collect(),
+                bytes: [0, 1, 2, 3, 4, 5].into_iter().collect(),
+                chars: ['a', 'b', 'c', 'd', 'e', 'f'].into_iter().collect(),
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct SplitTest<'a> {
+    #[garde(length(split = ",", min = 2, max = 3))]
+    v: &'a str,
+}
+
+#[test]
+fn split_length_valid() {
+    util::check_ok(&[SplitTest { v: "a,b,c" }, SplitTest { v: "a," }], &())
+}
+
+#[test]
+fn split_length_counts_empty_segments() {
+    use garde::rules::length::split;
+
+    assert!(split::apply(&"a,b,c", (",", (3, 3))).is_ok());
+    assert!(split::apply(&"a,", (",", (2, 2))).is_ok());
+    assert!(split::apply(&"", (",", (1, 1))).is_ok());
+}
+
+#[test]
+fn split_length_invalid() {
+    util::check_fail!(
+        &[
+            SplitTest { v: "" },
+            SplitTest { v: "a" },
+            SplitTest { v: "a,b,c," },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct RefTest<'a> {
+    #[garde(length(min = 1, max = 4))]
+    string: &'a &'a String,
+    #[garde(length(min = 1, max = 4))]
+    deque: &'a std::collections::VecDeque<u8>,
+    #[garde(length(min = 1, max = 4))]
+    slice: &'a &'a [u8],
+}
+
+#[test]
+fn ref_length_valid() {
+    let string = &String::from("abcd");
+    let slice: &[u8] = &[0];
+    util::check_ok(
+        &[RefTest {
+            string: &string,
+            deque: &[0, 1].into(),
+            slice: &slice,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn ref_length_invalid() {
+    let string = &String::from("abcde");
+    let slice: &[u8] = &[];
+    util::check_fail!(
+        &[RefTest {
+            string: &string,
+            deque: &Default::default(),
+            slice: &slice,
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct MutRefTest<'a> {
+    #[garde(
+        length(min = 1, max = 4),
+        length(bytes, min = 1, max = 4),
+        length(chars, min = 1, max = 2)
+    )]
+    string: &'a mut String,
+    #[garde(
+        length(min = 1, max = 4),
+        length(bytes, min = 1, max = 4),
+        length(chars, min = 1, max = 2)
+    )]
+    str: &'a mut str,
+}
+
+#[test]
+fn mut_ref_length_valid() {
+    let mut string = String::from("éa");
+    let mut str = String::from("é");
+    util::check_ok(
+        &[MutRefTest {
+            string: &mut string,
+            str: str.as_mut_str(),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn mut_ref_length_invalid() {
+    let mut string = String::from("ééé");
+    let mut str = String::new();
+    util::check_fail!(
+        &[MutRefTest {
+            string: &mut string,
+            str: str.as_mut_str(),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct MutRefBlanketTest<'a> {
+    #[garde(length(min = 1), length(bytes, max = 2))]
+    vec: &'a mut Vec<u8>,
+    #[garde(length(chars, min = 1))]
+    compact: &'a mut garde::external::compact_str::CompactString,
+}
+
+#[test]
+fn mut_ref_blanket_length() {
+    let mut vec = vec![1, 2, 3];
+    let mut compact = Default::default();
+    let report = garde::Validate::validate(&MutRefBlanketTest {
+        vec: &mut vec,
+        compact: &mut compact,
+    })
+    .unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "compact: length 0 is lower than minimum 1\nvec: length 3 is greater than maximum 2\n"
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct FixedArrayTest<'a> {
+    #[garde(length(min = 4, max = 8))]
+    boxed: Box<[u8; 4]>,
+    #[garde(length(min = 4, max = 8))]
+    borrowed: &'a mut [u8; 4],
+}
+
+#[test]
+fn fixed_array_length_valid() {
+    let mut array = [0u8; 4];
+    util::check_ok(
+        &[FixedArrayTest {
+            boxed: Box::new([0; 4]),
+            borrowed: &mut array,
+        }],
+        &(),
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct FixedArrayTooShortTest<'a> {
+    #[garde(length(min = 5))]
+    boxed: Box<[u8; 4]>,
+    #[garde(length(min = 5))]
+    borrowed: &'a mut [u8; 4],
+}
+
+#[test]
+fn fixed_array_length_invalid() {
+    let mut array = [0u8; 4];
+    util::check_fail!(
+        &[FixedArrayTooShortTest {
+            boxed: Box::new([0; 4]),
+            borrowed: &mut array,
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct SingleBoundTest {
+    #[garde(length(max = 4))]
+    max_only: String,
+    #[garde(length(min = 3))]
+    min_only: String,
+}
+
+#[test]
+fn single_bound_length_valid() {
+    util::check_ok(
+        &[
+            SingleBoundTest {
+                max_only: String::new(),
+                min_only: "a".repeat(1000),
+            },
+            SingleBoundTest {
+                max_only: "abcd".into(),
+                min_only: "abc".into(),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn single_bound_length_invalid() {
+    util::check_fail!(
+        &[SingleBoundTest {
+            max_only: "abcde".into(),
+            min_only: "ab".into(),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct CowSliceTest<'a> {
+    #[garde(length(min = 1, max = 4))]
+    simple: Cow<'a, [u8]>,
+    #[garde(length(bytes, min = 1, max = 4))]
+    bytes: Cow<'a, [u8]>,
+    #[garde(length(min = 1, max = 4))]
+    items: Cow<'a, [char]>,
+}
+
+#[test]
+fn cow_slice_length_valid() {
+    util::check_ok(
+        &[
+            CowSliceTest {
+                simple: Cow::Borrowed(b"ab"),
+                bytes: Cow::Borrowed(b"ab"),
+                items: Cow::Borrowed(&['a', 'b']),
+            },
+            CowSliceTest {
+                simple: Cow::Owned(b"abcd".to_vec()),
+                bytes: Cow::Owned(b"abcd".to_vec()),
+                items: Cow::Owned(vec!['a', 'b', 'c', 'd']),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn cow_slice_length_invalid() {
+    util::check_fail!(
+        &[
+            CowSliceTest {
+                simple: Cow::Borrowed(b""),
+                bytes: Cow::Borrowed(b""),
+                items: Cow::Borrowed(&[]),
+            },
+            CowSliceTest {
+                simple: Cow::Owned(b"abcde".to_vec()),
+                bytes: Cow::Owned(b"abcde".to_vec()),
+                items: Cow::Owned(vec!['a', 'b', 'c', 'd', 'e']),
+            },
+        ],
+        &()
+    )
+}
+
+#[allow(clippy::borrowed_box)]
+#[derive(Debug, garde::Validate)]
+struct BorrowedStrTest<'a> {
+    #[garde(
+        length(min = 1, max = 4),
+        length(bytes, min = 1, max = 4),
+        length(chars, min = 1, max = 2)
+    )]
+    cow: &'a Cow<'a, str>,
+    #[garde(
+        length(min = 1, max = 4),
+        length(bytes, min = 1, max = 4),
+        length(chars, min = 1, max = 2)
+    )]
+    boxed: &'a Box<str>,
+}
+
+#[test]
+fn borrowed_str_length_valid() {
+    let cow = Cow::Borrowed("éa");
+    let boxed: Box<str> = "é".into();
+    util::check_ok(
+        &[BorrowedStrTest {
+            cow: &cow,
+            boxed: &boxed,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn borrowed_str_length_invalid() {
+    let cow = Cow::Owned("ééé".to_string());
+    let boxed: Box<str> = "".into();
+    util::check_fail!(
+        &[BorrowedStrTest {
+            cow: &cow,
+            boxed: &boxed,
+        }],
+        &()
+    )
+}
+
+#[cfg(feature = "base64")]
+#[derive(Debug, garde::Validate)]
+struct Base64Test<'a> {
+    #[garde(length(base64_decoded, min = 1, max = 4))]
+    v: &'a str,
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64_decoded_length_valid() {
+    util::check_ok(
+        &[
+            Base64Test { v: "YQ==" },
+            Base64Test { v: "YQ" },
+            Base64Test { v: "YWJjZA==" },
+            Base64Test { v: "YWJjZA" },
+        ],
+        &(),
+    )
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64_decoded_length_invalid() {
+    util::check_fail!(
+        &[
+            Base64Test { v: "" },
+            Base64Test { v: "YWJjZGU=" },
+            Base64Test { v: "YWJjZ" },
+            Base64Test { v: "YQ=" },
+        ],
+        &()
+    )
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64_decoded_length_matches_decode() {
+    use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+    use base64::Engine as _;
+    use garde::rules::length::base64_decoded::decoded_len;
+
+    for len in 0..32 {
+        let input: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        for engine in [STANDARD, STANDARD_NO_PAD] {
+            let encoded = engine.encode(&input);
+            let decoded = engine.decode(&encoded).unwrap();
+            assert_eq!(decoded_len(&encoded), Some(decoded.len()), "{encoded:?}");
+        }
+    }
+    for invalid in ["a", "aaaaa", "a===", "aa=", "aaa=a"] {
+        assert_eq!(decoded_len(invalid), None, "{invalid:?}");
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct CompactStringTest {
+    #[garde(length(bytes, min = 1, max = 32))]
+    bytes: garde::external::compact_str::CompactString,
+    #[garde(length(chars, min = 1, max = 32))]
+    chars: garde::external::compact_str::CompactString,
+}
+
+impl CompactStringTest {
+    fn new(v: &str) -> Self {
+        Self {
+            bytes: v.into(),
+            chars: v.into(),
+        }
+    }
+}
+
+#[test]
+fn compact_string_length_valid() {
+    let inline = CompactStringTest::new("hello");
+    let heap = CompactStringTest::new(&"a".repeat(30));
+    assert!(!inline.bytes.is_heap_allocated());
+    assert!(heap.bytes.is_heap_allocated());
+
+    util::check_ok(&[inline, heap], &())
+}
+
+#[test]
+fn compact_string_length_invalid() {
+    let inline = CompactStringTest::new("");
+    let heap = CompactStringTest::new(&"a".repeat(40));
+    assert!(!inline.bytes.is_heap_allocated());
+    assert!(heap.bytes.is_heap_allocated());
+
+    util::check_fail!(&[inline, heap], &())
+}
+
+#[cfg(feature = "arrayvec")]
+#[derive(Debug, garde::Validate)]
+struct ArrayVecTest {
+    #[garde(length(min = 1, max = 4))]
+    vec: arrayvec::ArrayVec<u32, 8>,
+    #[garde(length(bytes, min = 1, max = 4))]
+    bytes: arrayvec::ArrayString<16>,
+    #[garde(length(chars, min = 1, max = 4))]
+    chars: arrayvec::ArrayString<16>,
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec_length_valid() {
+    util::check_ok(
+        &[ArrayVecTest {
+            vec: [1, 2, 3].into_iter().collect(),
+            bytes: arrayvec::ArrayString::from("áá").unwrap(),
+            chars: arrayvec::ArrayString::from("áááá").unwrap(),
+        }],
+        &(),
+    )
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec_length_invalid() {
+    util::check_fail!(
+        &[ArrayVecTest {
+            vec: [1, 2, 3, 4, 5].into_iter().collect(),
+            bytes: arrayvec::ArrayString::from("ááá").unwrap(),
+            chars: arrayvec::ArrayString::new(),
+        }],
+        &()
+    )
+}
+
+#[cfg(feature = "bytes")]
+#[derive(Debug, garde::Validate)]
+struct BytesTest {
+    #[garde(length(bytes, min = 4, max = 4))]
+    bytes: bytes::Bytes,
+    #[garde(length(min = 4, max = 4))]
+    bytes_mut: bytes::BytesMut,
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_length_valid() {
+    util::check_ok(
+        &[BytesTest {
+            bytes: bytes::Bytes::from_static(&[1, 2, 3, 4]),
+            bytes_mut: bytes::BytesMut::from(&[1, 2, 3, 4][..]),
+        }],
+        &(),
+    )
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_length_invalid() {
+    util::check_fail!(
+        &[BytesTest {
+            bytes: bytes::Bytes::from_static(&[1, 2, 3]),
+            bytes_mut: bytes::BytesMut::from(&[1, 2, 3, 4, 5][..]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn length_bound_messages() {
+    use garde::rules::length::{bytes, chars, simple};
+
+    let message = |result: Result<(), garde::Error>| result.unwrap_err().message().to_owned();
+
+    assert_eq!(
+        message(simple::apply(&"a", (2, 3))),
+        "length 1 is lower than minimum 2"
+    );
+    assert_eq!(
+        message(simple::apply(&"aaaa", (2, 3))),
+        "length 4 is greater than maximum 3"
+    );
+    assert_eq!(
+        message(chars::apply(&"á", (2, 3))),
+        "length 1 is lower than minimum 2"
+    );
+    assert_eq!(
+        message(chars::apply(&"áááá", (2, 3))),
+        "length 4 is greater than maximum 3"
+    );
+    assert_eq!(
+        message(bytes::apply(&"a", (2, 3))),
+        "length 1 is lower than minimum 2"
+    );
+    assert_eq!(
+        message(bytes::apply(&"áá", (2, 3))),
+        "length 4 is greater than maximum 3"
+    );
+    assert_eq!(
+        message(simple::apply(&"aaaa", (2, 2))),
+        "length 4 is not exactly 2"
+    );
+}
+
+#[test]
+fn bounds_from_tuple_roundtrip() {
+    use garde::rules::length::Bounds;
+
+    let bounds = Bounds::from((3, 5));
+    assert_eq!(bounds, Bounds::new(3, 5));
+    assert_eq!(bounds, Bounds { min: 3, max: 5 });
+    assert_eq!(<(usize, usize)>::from(bounds), (3, 5));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "`min` must be lower than or equal to `max`"]
+fn bounds_min_greater_than_max_panics() {
+    garde::rules::length::Bounds::new(5, 3);
+}
+
+#[test]
+fn chars_count_matches_decoded_chars() {
+    use garde::rules::length::HasChars;
+
+    let ascii = "a".repeat(10_000);
+    let mixed = "aá😂\n".repeat(2_500);
+    for input in ["", "a", "á", ascii.as_str(), mixed.as_str()] {
+        assert_eq!(input.num_chars(), input.chars().count());
+        assert_eq!(input.to_owned().num_chars(), input.chars().count());
+    }
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn apply_with_mode_counts_per_mode() {
+    use garde::rules::length::{apply_with_mode, LengthMode};
+
+    // 1 grapheme, 2 chars, 4 UTF-16 code units, 8 bytes
+    let flag = "🇨🇿";
+    for (mode, len) in [
+        (LengthMode::Bytes, 8),
+        (LengthMode::Chars, 2),
+        (LengthMode::Utf16, 4),
+        (LengthMode::Graphemes, 1),
+    ] {
+        assert!(apply_with_mode(&flag, (len, len), mode).is_ok(), "{mode:?}");
+        assert!(
+            apply_with_mode(&flag, (len + 1, len + 1), mode).is_err(),
+            "{mode:?}"
+        );
+    }
+
+    assert!(apply_with_mode(&None::<&str>, (1, 1), LengthMode::Chars).is_ok());
+}
+
+#[test]
+fn apply_all_collects_every_failure() {
+    use garde::rules::length::{apply_all, Bounds, LengthMode};
+
+    let checks = [
+        (LengthMode::Bytes, Bounds::new(0, 8)),
+        (LengthMode::Chars, Bounds::new(0, 4)),
+        (LengthMode::Utf16, Bounds::new(0, 100)),
+    ];
+    assert!(apply_all(&"áéíó", &checks).is_empty());
+
+    let errors = apply_all(&"áéíóú", &checks);
+    let errors: Vec<_> = errors.iter().map(|e| (e.kind(), e.message())).collect();
+    assert_eq!(
+        errors,
+        [
+            (
+                garde::ErrorKind::BytesOutOfRange,
+                "length 10 is greater than maximum 8"
+            ),
+            (
+                garde::ErrorKind::CharsOutOfRange,
+                "length 5 is greater than maximum 4"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn length_error_kind() {
+    use garde::rules::length::{bytes, chars, simple};
+    use garde::ErrorKind;
+
+    let kind = |result: Result<(), garde::Error>| result.unwrap_err().kind();
+
+    assert_eq!(
+        kind(simple::apply(&"a", (2, 3))),
+        ErrorKind::LengthOutOfRange
+    );
+    assert_eq!(kind(chars::apply(&"a", (2, 3))), ErrorKind::CharsOutOfRange);
+    assert_eq!(kind(bytes::apply(&"a", (2, 3))), ErrorKind::BytesOutOfRange);
+    assert_eq!(garde::Error::new("custom").kind(), ErrorKind::Custom);
+}
+
+#[derive(Debug, garde::Validate)]
+struct CustomMessage<'a> {
+    #[garde(length(min = 1, max = 10, message = "name too long"))]
+    name: &'a str,
+    #[garde(length(
+        chars,
+        min = 2,
+        max = 4,
+        message = "tag must have {min} to {max} chars"
+    ))]
+    tag: &'a str,
+    #[garde(length(max = 3, message = "at most {max} {{items}}"))]
+    items: &'a [u8],
+}
+
+#[test]
+fn length_custom_message() {
+    use garde::Validate as _;
+
+    let report = CustomMessage {
+        name: "abcdefghijk",
+        tag: "a",
+        items: &[1, 2, 3, 4],
+    }
+    .validate()
+    .unwrap_err();
+
+    let mut errors: Vec<_> = report
+        .iter()
+        .map(|(path, error)| {
+            (
+                path.to_string(),
+                error.message().to_string(),
+                error.kind(),
+                error.rule(),
+                error.length(),
+            )
+        })
+        .collect();
+    errors.sort();
+
+    assert_eq!(
+        errors,
+        [
+            (
+                "items".to_string(),
+                "at most 3 {items}".to_string(),
+                garde::ErrorKind::LengthOutOfRange,
+                "length",
+                Some(4)
+            ),
+            (
+                "name".to_string(),
+                "name too long".to_string(),
+                garde::ErrorKind::LengthOutOfRange,
+                "length",
+                Some(11)
+            ),
+            (
+                "tag".to_string(),
+                "tag must have 2 to 4 chars".to_string(),
+                garde::ErrorKind::CharsOutOfRange,
+                "chars",
+                Some(1)
+            ),
+        ]
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct WithCode<'a> {
+    #[garde(length(min = 1, max = 10, code = "name_length"))]
+    name: &'a str,
+    #[garde(length(min = 1, message = "required", code = "tag_length"))]
+    tag: &'a str,
+}
+
+#[test]
+fn length_code() {
+    use garde::Validate as _;
+
+    let report = WithCode {
+        name: "abcdefghijk",
+        tag: "",
+    }
+    .validate()
+    .unwrap_err();
+
+    let mut errors: Vec<_> = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code(), error.message().to_string()))
+        .collect();
+    errors.sort();
+
+    assert_eq!(
+        errors,
+        [
+            (
+                "name".to_string(),
+                Some("name_length"),
+                "length 11 is greater than maximum 10".to_string()
+            ),
+            (
+                "tag".to_string(),
+                Some("tag_length"),
+                "required".to_string()
+            ),
+        ]
+    );
+    assert_eq!(garde::Error::new("custom").code(), None);
+}
+
+#[test]
+fn length_apply_range() {
+    use garde::rules::length::{bytes, chars, simple};
+
+    for v in ["", "hello", "😂😂😂", "hello world"] {
+        assert_eq!(simple::apply_range(&v, 0..=10), simple::apply(&v, (0, 10)));
+        assert_eq!(bytes::apply_range(&v, 0..=10), bytes::apply(&v, (0, 10)));
+        assert_eq!(chars::apply_range(&v, 0..=10), chars::apply(&v, (0, 10)));
+        assert_eq!(chars::apply(&v, 1..=3), chars::apply(&v, (1, 3)));
+    }
+    assert!(simple::apply_range(&"hello world", 0..=10).is_err());
+}
+
+#[cfg(feature = "indexmap")]
+#[derive(Debug, garde::Validate)]
+struct IndexMapTest {
+    #[garde(length(min = 1, max = 5))]
+    map: indexmap::IndexMap<&'static str, u32, std::hash::RandomState>,
+    #[garde(length(min = 1, max = 5))]
+    set: indexmap::IndexSet<u32, std::hash::RandomState>,
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap_length_valid() {
+    util::check_ok(
+        &[IndexMapTest {
+            map: [("a", 0), ("b", 1), ("c", 2)].into_iter().collect(),
+            set: [0].into_iter().collect(),
+        }],
+        &(),
+    )
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap_length_invalid() {
+    util::check_fail!(
+        &[IndexMapTest {
+            map: indexmap::IndexMap::default(),
+            set: (0..6).collect(),
+        }],
+        &()
+    )
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Debug, garde::Validate)]
+struct HeaplessTest {
+    #[garde(length(min = 1, max = 4))]
+    vec: heapless::Vec<u8, 16>,
+    #[garde(length(bytes, min = 1, max = 4))]
+    bytes: heapless::String<32>,
+    #[garde(length(chars, min = 1, max = 4))]
+    chars: heapless::String<32>,
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_length_valid() {
+    util::check_ok(
+        &[HeaplessTest {
+            vec: heapless::Vec::from_slice(&[1, 2, 3]).unwrap(),
+            bytes: heapless::String::try_from("áá").unwrap(),
+            chars: heapless::String::try_from("áááá").unwrap(),
+        }],
+        &(),
+    )
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_length_invalid() {
+    util::check_fail!(
+        &[HeaplessTest {
+            vec: heapless::Vec::from_slice(&[1, 2, 3, 4, 5]).unwrap(),
+            bytes: heapless::String::try_from("ááá").unwrap(),
+            chars: heapless::String::new(),
+        }],
+        &()
+    )
+}
+
+#[cfg(feature = "generic-array")]
+#[derive(Debug, garde::Validate)]
+struct GenericArrayTest {
+    #[garde(length(min = 32, max = 32))]
+    key: generic_array::GenericArray<u8, generic_array::typenum::U32>,
+    #[garde(length(bytes, min = 32, max = 32))]
+    bytes: generic_array::GenericArray<u8, generic_array::typenum::U32>,
+    #[garde(length(min = 2))]
+    short: generic_array::GenericArray<u16, generic_array::typenum::U1>,
+}
+
+#[cfg(feature = "generic-array")]
+#[test]
+fn generic_array_length() {
+    let value = GenericArrayTest {
+        key: Default::default(),
+        bytes: Default::default(),
+        short: Default::default(),
+    };
+    let report = garde::Validate::validate(&value).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "short: length 1 is lower than minimum 2\n"
+    );
+}
+
+/// Only exposes its items through `iter`.
+#[derive(Debug)]
+struct Ring {
+    items: [u8; 8],
+    start: usize,
+    len: usize,
+}
+
+impl Ring {
+    fn iter(&self) -> impl Iterator<Item = &u8> + '_ {
+        (0..self.len).map(|i| &self.items[(self.start + i) % self.items.len()])
+    }
+}
+
+impl garde::rules::length::Countable for Ring {
+    type Iter<'a> = Box<dyn Iterator<Item = &'a u8> + 'a>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        Box::new(Ring::iter(self))
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct CountTest {
+    #[garde(length(count, min = 1, max = 5))]
+    ring: Ring,
+    #[garde(length(count, min = 1, max = 5))]
+    optional: Option<Ring>,
+}
+
+#[test]
+fn count_length_valid() {
+    util::check_ok(
+        &[CountTest {
+            ring: Ring {
+                items: [0; 8],
+                start: 6,
+                len: 5,
+            },
+            optional: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn count_length_invalid() {
+    util::check_fail!(
+        &[CountTest {
+            ring: Ring {
+                items: [0; 8],
+                start: 0,
+                len: 0,
+            },
+            optional: Some(Ring {
+                items: [0; 8],
+                start: 3,
+                len: 6,
+            }),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct TupleTest {
+    #[garde(length(min = 2, max = 2))]
+    pair: (u8, &'static str),
+    #[garde(length(min = 3, max = 3))]
+    triple: (u8, u8, u8),
+    #[garde(length(min = 1, max = 2))]
+    too_long: (u8, u8, u8),
+    #[garde(length(min = 3))]
+    too_short: (u8, u8),
+}
+
+#[test]
+fn tuple_length_invalid() {
+    util::check_fail!(
+        &[TupleTest {
+            pair: (0, "a"),
+            triple: (0, 1, 2),
+            too_long: (0, 1, 2),
+            too_short: (0, 1),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn tuple_length_arity() {
+    use garde::rules::length::{simple, HasSimpleLength as _};
+
+    assert_eq!((1,).length(), 1);
+    assert_eq!((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).length(), 12);
+    assert!(simple::apply(&(1, "a"), (2, 2)).is_ok());
+    assert!(simple::apply(&(1, 2, 3), (2, 2)).is_err());
+}
+
+#[derive(Debug, garde::Validate)]
+struct RangeTest {
+    #[garde(length(min = 1, max = 5))]
+    span: std::ops::Range<usize>,
+}
+
+#[test]
+fn range_length() {
+    use garde::rules::length::HasSimpleLength as _;
+
+    assert_eq!((2..7).length(), 5);
+    #[allow(clippy::reversed_empty_ranges)]
+    let inverted = 7..2;
+    assert_eq!(inverted.length(), 0);
+
+    util::check_ok(&[RangeTest { span: 2..7 }], &());
+    util::check_fail!(
+        &[RangeTest { span: 2..8 }, RangeTest { span: inverted }],
+        &()
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct ExplicitModeTest<'a> {
+    #[garde(byte_length(min = 10, max = 10))]
+    bytes: &'a str,
+    #[garde(char_length(min = 5, max = 5))]
+    chars: &'a str,
+}
+
+#[test]
+fn explicit_mode_length_valid() {
+    util::check_ok(
+        &[ExplicitModeTest {
+            bytes: "áéíóú",
+            chars: "áéíóú",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn explicit_mode_length_invalid() {
+    util::check_fail!(
+        &[ExplicitModeTest {
+            bytes: "aeiou",
+            chars: "áéíóúá",
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct StorageAndDisplayLimitTest<'a> {
+    #[garde(byte_length(max = 12), char_length(max = 4))]
+    field: &'a str,
+}
+
+#[test]
+fn combined_length_modes_valid() {
+    util::check_ok(&[StorageAndDisplayLimitTest { field: "áéíó" }], &())
+}
+
+#[test]
+fn combined_length_modes_invalid() {
+    util::check_fail!(
+        &[
+            // fails only the char limit
+            StorageAndDisplayLimitTest {
+                field: "áéíóú"
+            },
+            // fails both limits
+            StorageAndDisplayLimitTest {
+                field: "áéíóúáéíóú"
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn length_observed() {
+    use garde::rules::length::{bytes, chars, simple};
+
+    let under = simple::apply(&"ab", (5, 10)).unwrap_err();
+    assert_eq!(under.message(), "length 2 is lower than minimum 5");
+    assert_eq!(under.length(), Some(2));
+
+    let over = chars::apply(&"ááááááááááá", (0, 10)).unwrap_err();
+    assert_eq!(over.message(), "length 11 is greater than maximum 10");
+    assert_eq!(over.length(), Some(11));
+
+    // counting chars stops after `max + 1`, so the exact length is not known
+    let far_over = chars::apply(&"ááááááááááááááá", (0, 10)).unwrap_err();
+    assert_eq!(far_over.message(), "length is greater than maximum 10");
+    assert_eq!(far_over.length(), None);
+
+    let exact = bytes::apply(&"á", (1, 1)).unwrap_err();
+    assert_eq!(exact.length(), Some(2));
+    assert_eq!(garde::Error::new("custom").length(), None);
+}
+
+#[test]
+fn length_rule_name() {
+    use garde::rules::length::{bytes, chars, check_len, simple};
+
+    assert_eq!(bytes::apply(&"ab", (5, 10)).unwrap_err().rule(), "bytes");
+    assert_eq!(chars::apply(&"ab", (5, 10)).unwrap_err().rule(), "chars");
+    assert_eq!(simple::apply(&"ab", (5, 10)).unwrap_err().rule(), "length");
+    assert_eq!(check_len(2, 5, 10).unwrap_err().rule(), "length");
+    assert_eq!(garde::Error::new("custom").rule(), "");
+}
+
+/// Yields `'a'` forever, and panics if more than `max_polls` chars are requested.
+struct BoundedChars {
+    max_polls: usize,
+}
+
+impl garde::rules::length::HasChars for BoundedChars {
+    fn num_chars(&self) -> usize {
+        panic!("counted every char")
+    }
+
+    fn num_chars_up_to(&self, limit: usize) -> Option<usize> {
+        let mut polls = 0;
+        let chars = std::iter::repeat_with(|| {
+            polls += 1;
+            assert!(polls <= self.max_polls, "polled {polls} chars");
+            'a'
+        });
+        garde::rules::length::chars::count_up_to(chars, limit)
+    }
+}
+
+#[test]
+fn chars_length_stops_past_max() {
+    use garde::rules::length::chars;
+
+    // `count_up_to` needs one extra item to observe that the limit of `max + 1` was exceeded
+    let error = chars::apply(&BoundedChars { max_polls: 12 }, (0, 10)).unwrap_err();
+    assert_eq!(error.message(), "length is greater than maximum 10");
+
+    // strings stop decoding after exactly `max + 1` chars
+    let long = "é".repeat(1_000_000);
+    let error = chars::apply(&long.as_str(), (0, 10)).unwrap_err();
+    assert_eq!(error.kind(), garde::ErrorKind::CharsOutOfRange);
+    assert_eq!(error.length(), None);
+    let error = chars::apply(&long.as_str(), (10, 10)).unwrap_err();
+    assert_eq!(error.message(), "length is not exactly 10");
+    assert!(chars::apply(&long.as_str(), (0, 1_000_000)).is_ok());
+}
+
+#[derive(Debug, garde::Validate)]
+struct TrimmedTest<'a> {
+    #[garde(length(trimmed, chars, min = 1, max = 2))]
+    chars: &'a str,
+    #[garde(length(trimmed, min = 2, max = 2))]
+    bytes: String,
+    #[garde(length(trimmed, utf16, max = 0))]
+    blank: Option<&'a str>,
+}
+
+#[test]
+fn trimmed_length_valid() {
+    util::check_ok(
+        &[TrimmedTest {
+            chars: "  hi  ",
+            bytes: "\u{3000}é\t".into(),
+            blank: Some(" \u{a0}\n "),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn trimmed_length_invalid() {
+    util::check_fail!(
+        &[TrimmedTest {
+            chars: "   ",
+            bytes: " a b ".into(),
+            blank: Some(" x "),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn trimmed_length_measure() {
+    use garde::rules::length::{trimmed, LengthMode};
+
+    assert!(trimmed::apply(&"  hi  ", (LengthMode::Chars, (2, 2))).is_ok());
+    assert_eq!(
+        trimmed::apply(&" \t\n ", (LengthMode::Chars, (1, 1)))
+            .unwrap_err()
+            .length(),
+        Some(0)
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct OptionalBorrowedTest<'a> {
+    #[garde(
+        length(min = 1, max = 2),
+        length(bytes, min = 1, max = 2),
+        length(chars, min = 1, max = 2)
+    )]
+    str_ref: Option<&'a str>,
+    #[garde(
+        length(min = 1, max = 2),
+        length(bytes, min = 1, max = 2),
+        length(chars, min = 1, max = 2)
+    )]
+    cow: Option<Cow<'a, str>>,
+    #[garde(length(min = 1, max = 2), length(bytes, min = 1, max = 2))]
+    bytes_ref: Option<&'a [u8]>,
+}
+
+#[test]
+fn optional_borrowed_length_valid() {
+    util::check_ok(
+        &[
+            OptionalBorrowedTest {
+                str_ref: Some("hi"),
+                cow: Some(Cow::Borrowed("hi")),
+                bytes_ref: Some(b"hi"),
+            },
+            OptionalBorrowedTest {
+                str_ref: None,
+                cow: None,
+                bytes_ref: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn optional_borrowed_length_invalid() {
+    util::check_fail!(
+        &[OptionalBorrowedTest {
+            str_ref: Some(""),
+            cow: Some(Cow::Owned("hey".into())),
+            bytes_ref: Some(b"hey"),
+        }],
+        &()
+    )
+}
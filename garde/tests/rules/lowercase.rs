@@ -0,0 +1,43 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(lowercase)]
+    field: &'a str,
+    #[garde(inner(lowercase))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn lowercase_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "abc",
+                inner: &["123-abc"],
+            },
+            Test {
+                field: "straße",
+                inner: &["", "-_.0123"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn lowercase_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "aBc",
+                inner: &["abC"],
+            },
+            Test {
+                field: "ÉCOLE",
+                inner: &[],
+            },
+        ],
+        &()
+    )
+}
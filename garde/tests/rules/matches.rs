@@ -35,3 +35,39 @@ fn matches_invalid() {
         &(),
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Signup<'a> {
+    #[garde(length(min = 8))]
+    password: &'a str,
+    #[garde(matches(password))]
+    confirm_password: &'a str,
+}
+
+#[test]
+fn matches_password_confirmation_valid() {
+    util::check_ok(
+        &[Signup {
+            password: "hunter22",
+            confirm_password: "hunter22",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn matches_password_confirmation_invalid() {
+    util::check_fail!(
+        &[
+            Signup {
+                password: "hunter22",
+                confirm_password: "hunter2",
+            },
+            Signup {
+                password: "hunter22",
+                confirm_password: "HUNTER22",
+            },
+        ],
+        &(),
+    )
+}
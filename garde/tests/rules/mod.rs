@@ -2,26 +2,50 @@ mod adapt;
 mod allow_unvalidated;
 mod alphanumeric;
 mod ascii;
+mod ascii_alphanumeric;
+mod bits;
+mod bounded;
+mod char_range;
 mod contains;
 mod credit_card;
 mod custom;
+mod datetime;
 mod dive;
 mod dive_with_rules;
 mod email;
+mod first_error;
+mod hex;
 mod inner;
 mod ip;
+mod keys;
 mod length;
+mod lowercase;
 mod matches;
 mod multi_rule;
+mod multiple_of;
 mod newtype;
+mod non_empty;
+mod not_contains;
+mod one_of;
 mod option;
 mod pattern;
+mod phone;
 mod phone_number;
 mod prefix;
+mod prefix_any;
 mod range;
+mod rename;
+mod report;
+mod required;
 mod select;
+mod severity;
 mod skip;
+mod skip_if;
 mod suffix;
+mod suffix_any;
+mod text_hygiene;
+mod uppercase;
 mod url;
+mod uuid;
 
 mod util;
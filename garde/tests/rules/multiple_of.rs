@@ -0,0 +1,66 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(multiple_of(5))]
+    cents: u64,
+    #[garde(multiple_of(5))]
+    signed: i32,
+    #[garde(inner(multiple_of(self.cents)))]
+    optional: Option<u64>,
+}
+
+#[test]
+fn multiple_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                cents: 0,
+                signed: 0,
+                optional: None,
+            },
+            Test {
+                cents: 25,
+                signed: 15,
+                optional: Some(50),
+            },
+            // negative values are multiples if their magnitude is
+            Test {
+                cents: 5,
+                signed: -10,
+                optional: Some(5),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn multiple_of_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                cents: 24,
+                signed: -11,
+                optional: Some(7),
+            },
+            // a zero divisor computed at runtime fails validation
+            Test {
+                cents: 0,
+                signed: 5,
+                optional: Some(0),
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn multiple_of_negative_divisor() {
+    use garde::rules::multiple_of;
+
+    assert!(multiple_of::apply(&-10i32, (-5,)).is_ok());
+    assert!(multiple_of::apply(&10i32, (-5,)).is_ok());
+    assert!(multiple_of::apply(&i32::MIN, (-1,)).is_ok());
+    assert!(multiple_of::apply(&7i32, (-5,)).is_err());
+}
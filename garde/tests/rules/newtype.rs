@@ -63,3 +63,34 @@ fn newtype_fields_invalid() {
         &()
     );
 }
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Name(#[garde(length(min = 1, max = 8))] String);
+
+#[derive(Debug, garde::Validate)]
+struct Person {
+    #[garde(dive)]
+    name: Name,
+}
+
+#[test]
+fn newtype_owned_string() {
+    use garde::Validate as _;
+
+    assert!(Name("Alice".into()).validate().is_ok());
+
+    let report = Name("Bartholomew".into()).validate().unwrap_err();
+    assert_eq!(report.to_string(), "length 11 is greater than maximum 8\n");
+
+    // the inner field does not add a path component
+    let report = Person {
+        name: Name(String::new()),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "name: length 0 is lower than minimum 1\n"
+    );
+}
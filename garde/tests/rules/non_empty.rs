@@ -0,0 +1,42 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(non_empty)]
+    vec: Vec<u32>,
+    #[garde(non_empty)]
+    string: String,
+    #[garde(non_empty)]
+    optional: Option<String>,
+}
+
+#[test]
+fn non_empty_valid() {
+    util::check_ok(
+        &[
+            Test {
+                vec: vec![0],
+                string: "a".into(),
+                optional: None,
+            },
+            Test {
+                vec: vec![0, 1],
+                string: " ".into(),
+                optional: Some("a".into()),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn non_empty_invalid() {
+    util::check_fail!(
+        &[Test {
+            vec: vec![],
+            string: "".into(),
+            optional: Some("".into()),
+        }],
+        &()
+    )
+}
@@ -0,0 +1,37 @@
+use super::util;
+
+const DASHES: &str = "--";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_contains(DASHES))]
+    field: &'a str,
+    #[garde(not_contains("<script"))]
+    optional: Option<&'a str>,
+    #[garde(inner(not_contains("--")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn not_contains_valid() {
+    util::check_ok(
+        &[Test {
+            field: "a-b-c",
+            optional: Some("plain"),
+            inner: &["-", "a-"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn not_contains_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "a--b",
+            optional: Some("<script>alert(1)</script>"),
+            inner: &["--"],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,44 @@
+use super::util;
+
+const BLUE: &str = "blue";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of("red", "green", BLUE))]
+    color: &'a str,
+    #[garde(one_of("s", "m", "l"))]
+    size: Option<String>,
+    #[garde(inner(one_of("a", "b")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn one_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                color: "red",
+                size: None,
+                inner: &["a", "b"],
+            },
+            Test {
+                color: "blue",
+                size: Some("m".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn one_of_invalid() {
+    util::check_fail!(
+        &[Test {
+            color: "Red",
+            size: Some("xl".into()),
+            inner: &["a", "c"],
+        }],
+        &()
+    )
+}
@@ -81,3 +81,47 @@ fn pattern_invalid() {
         &()
     )
 }
+
+struct Digits;
+
+impl garde::rules::AsStr for Digits {
+    fn as_str(&self) -> &str {
+        "[0-9]+"
+    }
+}
+
+impl garde::rules::pattern::Matcher for Digits {
+    fn is_match(&self, haystack: &str) -> bool {
+        !haystack.is_empty() && haystack.bytes().all(|b| b.is_ascii_digit())
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct CustomMatcher<'a> {
+    #[garde(pattern(Digits))]
+    field: &'a str,
+    #[garde(inner(pattern(Digits)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn pattern_custom_matcher_valid() {
+    util::check_ok(
+        &[CustomMatcher {
+            field: "0123",
+            inner: &["9"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn pattern_custom_matcher_invalid() {
+    util::check_fail!(
+        &[CustomMatcher {
+            field: "12a",
+            inner: &[""],
+        }],
+        &()
+    )
+}
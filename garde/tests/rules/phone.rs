@@ -0,0 +1,47 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(phone)]
+    field: &'a str,
+    #[garde(inner(phone))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn phone_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "+14155552671",
+                inner: &["14155552671"],
+            },
+            Test {
+                field: "+1",
+                inner: &["123456789012345"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn phone_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "+0123",
+                inner: &["+1415555abcd"],
+            },
+            Test {
+                field: "+",
+                inner: &["1234567890123456"],
+            },
+            Test {
+                field: "+1 415 555 2671",
+                inner: &["++14155552671"],
+            },
+        ],
+        &()
+    )
+}
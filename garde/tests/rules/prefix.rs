@@ -43,3 +43,14 @@ fn prefix_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Empty<'a> {
+    #[garde(prefix(""))]
+    field: &'a str,
+}
+
+#[test]
+fn prefix_empty_always_valid() {
+    util::check_ok(&[Empty { field: "" }, Empty { field: "anything" }], &())
+}
@@ -0,0 +1,39 @@
+use super::util;
+
+const HTTPS: &str = "https://";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(prefix_any("http://", HTTPS))]
+    field: &'a str,
+    #[garde(inner(prefix_any("http://", HTTPS)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn prefix_any_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "http://example.com",
+                inner: &["https://example.com"],
+            },
+            Test {
+                field: "https://example.com",
+                inner: &["http://example.com"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn prefix_any_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "ftp://example.com",
+            inner: &["example.com"],
+        }],
+        &()
+    )
+}
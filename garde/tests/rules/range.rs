@@ -126,3 +126,70 @@ fn min_max_equal_length_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Float {
+    #[garde(range(min = 0.0, max = 100.0))]
+    bounded: f64,
+    #[garde(range(min = 0.0))]
+    min_only: f64,
+    #[garde(range(min = f64::NEG_INFINITY, max = f64::INFINITY))]
+    infinite: f64,
+}
+
+#[test]
+fn float_range_valid() {
+    util::check_ok(
+        &[
+            Float {
+                bounded: 0.0,
+                min_only: f64::INFINITY,
+                infinite: f64::NEG_INFINITY,
+            },
+            Float {
+                bounded: 100.0,
+                min_only: 0.0,
+                infinite: f64::INFINITY,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn float_range_invalid() {
+    util::check_fail!(
+        &[
+            Float {
+                bounded: -0.5,
+                min_only: f64::NEG_INFINITY,
+                infinite: f64::NAN,
+            },
+            Float {
+                bounded: f64::NAN,
+                min_only: f64::NAN,
+                infinite: 0.0,
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct CustomMessage {
+    #[garde(range(min = 18, message = "must be at least {min} years old"))]
+    age: u8,
+}
+
+#[test]
+fn range_custom_message() {
+    use garde::Validate as _;
+
+    let report = CustomMessage { age: 17 }.validate().unwrap_err();
+    let [(path, error)] = &report.into_inner()[..] else {
+        panic!("expected exactly one error");
+    };
+    assert_eq!(path.to_string(), "age");
+    assert_eq!(error.message(), "must be at least 18 years old");
+    assert_eq!(error.kind(), garde::ErrorKind::OutOfRange);
+}
@@ -0,0 +1,79 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(rename_all = "camelCase")]
+struct User<'a> {
+    #[garde(length(min = 1))]
+    first_name: &'a str,
+    #[garde(rename = "surname", length(min = 1))]
+    last_name: &'a str,
+    #[garde(dive)]
+    home_address: Address<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(rename("zip"), length(max = 5))]
+    zip_code: &'a str,
+}
+
+#[test]
+fn rename_invalid() {
+    util::check_fail!(
+        &[User {
+            first_name: "",
+            last_name: "",
+            home_address: Address { zip_code: "123456" },
+        }],
+        &()
+    )
+}
+
+#[test]
+fn rename_used_in_path() {
+    let user = User {
+        first_name: "",
+        last_name: "",
+        home_address: Address { zip_code: "123456" },
+    };
+    let report = garde::Validate::validate(&user).unwrap_err();
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["firstName", "homeAddress.zip", "surname"]);
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(rename_all = "SCREAMING-KEBAB-CASE")]
+struct Kebab<'a> {
+    #[garde(length(min = 1))]
+    r#type: &'a str,
+    #[garde(length(min = 1))]
+    max_len: &'a str,
+}
+
+#[test]
+fn rename_all_cases() {
+    let value = Kebab {
+        r#type: "",
+        max_len: "",
+    };
+    let report = garde::Validate::validate(&value).unwrap_err();
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["MAX-LEN", "TYPE"]);
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(rename_all = "camelCase")]
+enum Event<'a> {
+    Login {
+        #[garde(length(min = 1))]
+        user_name: &'a str,
+    },
+}
+
+#[test]
+fn rename_all_enum_struct_variant() {
+    let value = Event::Login { user_name: "" };
+    let report = garde::Validate::validate(&value).unwrap_err();
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["userName"]);
+}
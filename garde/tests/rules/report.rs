@@ -0,0 +1,328 @@
+use garde::error::Path;
+use garde::{Report, Validate};
+
+#[derive(Debug, Clone, PartialEq, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+    #[garde(length(max = 5))]
+    zip: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct User<'a> {
+    #[garde(length(min = 3))]
+    name: &'a str,
+    #[garde(dive)]
+    addresses: Vec<Address<'a>>,
+}
+
+#[test]
+fn report_collects_every_error_with_path() {
+    let user = User {
+        name: "ab",
+        addresses: vec![
+            Address {
+                street: "Main St",
+                zip: "12345",
+            },
+            Address {
+                street: "",
+                zip: "123456",
+            },
+        ],
+    };
+
+    let report = user.validate().unwrap_err();
+    let mut errors: Vec<(String, String)> = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.to_string()))
+        .collect();
+    errors.sort();
+    assert_eq!(
+        errors,
+        [
+            (
+                "addresses[1].street".to_owned(),
+                "length 0 is lower than minimum 1".to_owned()
+            ),
+            (
+                "addresses[1].zip".to_owned(),
+                "length 6 is greater than maximum 5".to_owned()
+            ),
+            (
+                "name".to_owned(),
+                "length 2 is lower than minimum 3".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn report_shared_across_batch() {
+    let batch = [
+        Address {
+            street: "",
+            zip: "123456",
+        },
+        Address {
+            street: "Main St",
+            zip: "12345",
+        },
+        Address {
+            street: "",
+            zip: "1",
+        },
+    ];
+
+    let mut report = Report::new();
+    for (index, address) in batch.iter().enumerate() {
+        address.validate_into(&(), &mut || Path::new(index), &mut report);
+    }
+    assert_eq!(report.len(), 3);
+    assert_eq!(report.iter().next().unwrap().0.to_string(), "[0].street");
+
+    report.clear();
+    assert!(report.is_empty());
+    batch[1].validate_into(&(), &mut Path::empty, &mut report);
+    assert!(report.is_empty());
+}
+
+#[test]
+fn report_into_result_with_question_mark() {
+    fn validate_all(batch: &[Address<'_>]) -> Result<usize, Report> {
+        let mut report = Report::new();
+        for (index, address) in batch.iter().enumerate() {
+            address.validate_into(&(), &mut || Path::new(index), &mut report);
+        }
+        report.into_result()?;
+        Ok(batch.len())
+    }
+
+    let batch = [
+        Address {
+            street: "Main St",
+            zip: "12345",
+        },
+        Address {
+            street: "",
+            zip: "12345",
+        },
+    ];
+
+    assert_eq!(validate_all(&batch[..1]).unwrap(), 1);
+    let report = validate_all(&batch).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "[1].street: length 0 is lower than minimum 1\n"
+    );
+    assert!(Report::new().into_result().is_ok());
+}
+
+#[test]
+fn validate_iter_yields_failing_indices() {
+    let failing = [3, 250, 251, 999];
+    let addresses = (0..1000).map(|index| Address {
+        street: if failing.contains(&index) {
+            ""
+        } else {
+            "Main St"
+        },
+        zip: "12345",
+    });
+
+    let results: Vec<(usize, Report)> = garde::validate_iter(addresses).collect();
+    let indices: Vec<usize> = results.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, failing);
+    for (_, report) in &results {
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.iter().next().unwrap().0.to_string(), "street");
+    }
+}
+
+#[test]
+fn validate_and_collect_keeps_value() {
+    let address = Address {
+        street: "",
+        zip: "123456",
+    };
+    let (value, report) = garde::validate_and_collect(address.clone());
+    assert_eq!(value, address);
+    assert!(!report.is_valid());
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["street", "zip"]);
+
+    let address = Address {
+        street: "Main St",
+        zip: "12345",
+    };
+    let (value, report) = garde::validate_and_collect(address.clone());
+    assert_eq!(value, address);
+    assert!(report.is_empty());
+}
+
+#[derive(Debug, garde::Validate)]
+struct Directory<'a> {
+    #[garde(dive)]
+    users: std::collections::BTreeMap<&'a str, User<'a>>,
+}
+
+#[test]
+fn report_display_with_paths() {
+    let directory = Directory {
+        users: [(
+            "alice",
+            User {
+                name: "ab",
+                addresses: vec![
+                    Address {
+                        street: "Main St",
+                        zip: "12345",
+                    },
+                    Address {
+                        street: "",
+                        zip: "123",
+                    },
+                ],
+            },
+        )]
+        .into_iter()
+        .collect(),
+    };
+
+    let report = directory.validate().unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "users.alice.addresses[1].street: length 0 is lower than minimum 1\n\
+         users.alice.name: length 2 is lower than minimum 3\n"
+    );
+    assert_eq!(
+        report.display_with_paths(" => ").to_string(),
+        "users.alice.addresses[1].street => length 0 is lower than minimum 1\n\
+         users.alice.name => length 2 is lower than minimum 3\n"
+    );
+}
+
+#[test]
+fn report_merge_prefixes_paths() {
+    let shipping = Address {
+        street: "",
+        zip: "12345",
+    };
+    let billing = Address {
+        street: "Main St",
+        zip: "123456",
+    };
+    let user = User {
+        name: "ab",
+        addresses: vec![],
+    };
+
+    let mut report = Report::new();
+    report.merge("shipping", shipping.validate().unwrap_err());
+    report.merge("billing", billing.validate().unwrap_err());
+    report.merge("user", user.validate().unwrap_err());
+
+    let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["shipping.street", "billing.zip", "user.name"]);
+
+    let mut nested = Report::new();
+    nested.append(
+        Path::new("items").join(1usize),
+        garde::Error::new("bad item"),
+    );
+    nested.append(Path::empty(), garde::Error::new("bad order"));
+    let mut report = Report::new();
+    report.merge("order", nested);
+    assert_eq!(
+        report.to_string(),
+        "order.items[1]: bad item\norder: bad order\n"
+    );
+}
+
+#[test]
+fn report_with_capacity_does_not_reallocate() {
+    let mut report = Report::with_capacity(4);
+    let capacity = report.capacity();
+    assert!(capacity >= 4);
+    for index in 0..4 {
+        report.append(Path::new(index), garde::Error::new("invalid"));
+        assert_eq!(report.capacity(), capacity);
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Wide<'a> {
+    #[garde(length(min = 1))]
+    a: &'a str,
+    #[garde(length(min = 1))]
+    b: &'a str,
+    #[garde(skip)]
+    _c: &'a str,
+    #[garde(dive)]
+    address: Address<'a>,
+}
+
+#[test]
+fn derived_report_capacity_counts_validated_fields() {
+    let address = Address {
+        street: "",
+        zip: "",
+    };
+    assert_eq!(address.report_capacity(), 2);
+
+    let wide = Wide {
+        a: "",
+        b: "",
+        _c: "",
+        address,
+    };
+    assert_eq!(wide.report_capacity(), 3);
+}
+
+#[test]
+fn wrapped_report_capacity_is_forwarded() {
+    let address = Address {
+        street: "",
+        zip: "",
+    };
+    assert_eq!(<&Address as Validate>::report_capacity(&&address), 2);
+    assert_eq!(Some(&address).report_capacity(), 2);
+    assert_eq!(None::<&Address>.report_capacity(), 0);
+    assert_eq!(Box::new(address).report_capacity(), 2);
+}
+
+#[derive(Debug, garde::Validate)]
+struct Signup<'a> {
+    #[garde(ascii, length(min = 3))]
+    username: &'a str,
+    #[garde(dive)]
+    address: Address<'a>,
+}
+
+#[test]
+fn report_into_grouped_by_path() {
+    let signup = Signup {
+        username: "é",
+        address: Address {
+            street: "",
+            zip: "12345",
+        },
+    };
+    let grouped = signup.validate().unwrap_err().into_grouped();
+    let expected: std::collections::BTreeMap<String, Vec<String>> = [
+        (
+            "address.street".to_string(),
+            vec!["length 0 is lower than minimum 1".to_string()],
+        ),
+        (
+            "username".to_string(),
+            vec![
+                "not ascii, found 'é' at index 0".to_string(),
+                "length 2 is lower than minimum 3".to_string(),
+            ],
+        ),
+    ]
+    .into();
+    assert_eq!(grouped, expected);
+}
@@ -0,0 +1,84 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, max = 10), required)]
+    required: Option<&'a str>,
+
+    #[garde(length(min = 1, max = 10))]
+    optional: Option<&'a str>,
+}
+
+#[test]
+fn required_length_valid() {
+    util::check_ok(
+        &[
+            Test {
+                required: Some("a"),
+                optional: Some("a"),
+            },
+            Test {
+                required: Some("aaaaaaaaaa"),
+                optional: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn required_length_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                required: None,
+                optional: None,
+            },
+            Test {
+                required: Some(""),
+                optional: Some(""),
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Presence<'a> {
+    #[garde(required)]
+    string: &'a str,
+    #[garde(required, inner(required))]
+    option: Option<&'a str>,
+}
+
+#[test]
+fn required_presence_valid() {
+    util::check_ok(
+        &[Presence {
+            string: "a",
+            option: Some(" a "),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn required_presence_invalid() {
+    util::check_fail!(
+        &[
+            Presence {
+                string: "",
+                option: None,
+            },
+            Presence {
+                string: " \t\n",
+                option: Some(""),
+            },
+            Presence {
+                string: "a",
+                option: Some("   "),
+            },
+        ],
+        &()
+    )
+}
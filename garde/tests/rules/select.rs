@@ -32,6 +32,12 @@ fn select_macro() {
         let errors: Vec<String> = garde::select!(report, name)
             .map(|e| e.to_string())
             .collect();
-        assert_eq!(errors, ["not ascii", "length is lower than 10"])
+        assert_eq!(
+            errors,
+            [
+                "not ascii, found '😂' at index 0",
+                "length 4 is lower than minimum 10"
+            ]
+        )
     }
 }
@@ -0,0 +1,62 @@
+use garde::{Report, Validate};
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 12, severity = "warning"))]
+    password: &'a str,
+    #[garde(range(max = 10, severity = "error"))]
+    retries: u32,
+}
+
+#[test]
+fn warnings_do_not_fail_validation() {
+    let value = Test {
+        password: "hunter2",
+        retries: 3,
+    };
+    assert!(value.validate().is_ok());
+
+    let mut report = Report::new();
+    value.validate_into(&(), &mut garde::Path::empty, &mut report);
+    assert!(report.is_valid());
+    assert!(!report.is_empty());
+    assert_eq!(report.errors().count(), 0);
+    let warnings: Vec<_> = report.warnings().collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].0.to_string(), "password");
+    assert_eq!(warnings[0].1.message(), "length 7 is lower than minimum 12");
+}
+
+#[test]
+fn errors_alongside_warnings() {
+    let value = Test {
+        password: "hunter2",
+        retries: 11,
+    };
+    let report = value.validate().unwrap_err();
+    assert_eq!(report.errors().count(), 1);
+    assert_eq!(report.warnings().count(), 1);
+}
+
+fn warn_if_short(value: &str, _: &()) -> garde::Result {
+    match value.len() < 4 {
+        true => Err(garde::Error::new("short").with_severity(garde::error::Severity::Warning)),
+        false => Ok(()),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct CustomWarning<'a> {
+    #[garde(custom(warn_if_short))]
+    name: &'a str,
+}
+
+#[test]
+fn custom_rule_warning() {
+    let value = CustomWarning { name: "abc" };
+    assert!(value.validate().is_ok());
+
+    let mut report = Report::new();
+    value.validate_into(&(), &mut garde::Path::empty, &mut report);
+    assert_eq!(report.warnings().count(), 1);
+}
@@ -0,0 +1,59 @@
+use super::util;
+
+fn no_billing(v: &Checkout, _: &()) -> bool {
+    !v.has_billing
+}
+
+#[derive(Debug, garde::Validate)]
+struct Checkout<'a> {
+    #[garde(skip)]
+    has_billing: bool,
+    #[garde(skip_if = no_billing, length(equal = 5))]
+    billing_zip: &'a str,
+}
+
+#[test]
+fn skip_if_valid() {
+    util::check_ok(
+        &[
+            Checkout {
+                has_billing: false,
+                billing_zip: "",
+            },
+            Checkout {
+                has_billing: true,
+                billing_zip: "12345",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn skip_if_invalid() {
+    util::check_fail!(
+        &[Checkout {
+            has_billing: true,
+            billing_zip: "",
+        }],
+        &()
+    )
+}
+
+struct Ctx {
+    lenient: bool,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Ctx))]
+struct WithContext<'a> {
+    #[garde(skip_if = |_: &Self, ctx: &Ctx| ctx.lenient, ascii)]
+    name: &'a str,
+}
+
+#[test]
+fn skip_if_context() {
+    let value = WithContext { name: "😂" };
+    util::check_ok(&[WithContext { name: "😂" }], &Ctx { lenient: true });
+    assert!(garde::Validate::validate_with(&value, &Ctx { lenient: false }).is_err());
+}
@@ -43,3 +43,14 @@ fn suffix_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Empty<'a> {
+    #[garde(suffix(""))]
+    field: &'a str,
+}
+
+#[test]
+fn suffix_empty_always_valid() {
+    util::check_ok(&[Empty { field: "" }, Empty { field: "anything" }], &())
+}
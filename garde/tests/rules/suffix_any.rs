@@ -0,0 +1,37 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(suffix_any(".png", ".jpg"))]
+    field: &'a str,
+    #[garde(suffix_any(".png", ".jpg"))]
+    optional: Option<&'a str>,
+}
+
+#[test]
+fn suffix_any_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "image.png",
+                optional: None,
+            },
+            Test {
+                field: "image.jpg",
+                optional: Some("image.png"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn suffix_any_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "image.gif",
+            optional: Some("png"),
+        }],
+        &()
+    )
+}
@@ -0,0 +1,43 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(no_trailing_whitespace, final_newline)]
+    file: &'a str,
+    #[garde(inner(no_trailing_whitespace))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn text_hygiene_valid() {
+    util::check_ok(
+        &[
+            Test {
+                file: "fn main() {}\n\n// end\n",
+                inner: &["a\r\nb", ""],
+            },
+            Test {
+                file: "",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn text_hygiene_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                file: "line 0\nline 1  \nline 2\t\n",
+                inner: &["a\t"],
+            },
+            Test {
+                file: "no final newline",
+                inner: &["ok", " \n"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,43 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(uppercase)]
+    field: &'a str,
+    #[garde(inner(uppercase))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn uppercase_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "ABC",
+                inner: &["123-ABC"],
+            },
+            Test {
+                field: "ÉCOLE",
+                inner: &["", "-_.0123"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn uppercase_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "AbC",
+                inner: &["ABc"],
+            },
+            Test {
+                field: "straße",
+                inner: &[],
+            },
+        ],
+        &()
+    )
+}
@@ -109,3 +109,25 @@ fn url_valid_wrapper() {
     };
     println!("{:?}", value.validate().unwrap_err());
 }
+
+#[test]
+fn url_absolute_and_query_valid() {
+    util::check_ok(
+        &[Struct {
+            field: "https://example.com/",
+            inner: &["https://example.com/search?q=garde&page=2"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn url_relative_invalid() {
+    util::check_fail!(
+        &[Struct {
+            field: "/relative/path",
+            inner: &["relative/path?q=1"]
+        }],
+        &()
+    )
+}
@@ -0,0 +1,54 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(uuid)]
+    field: &'a str,
+    #[garde(uuid_any)]
+    any: &'a str,
+    #[garde(inner(uuid))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn uuid_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                any: "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+                inner: &["00000000-0000-0000-0000-000000000000"],
+            },
+            Test {
+                field: "67E55044-10B1-426F-9247-BB680E5FE0C8",
+                any: "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+                inner: &[],
+            },
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                any: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn uuid_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "67e5504410b1-426f-9247-bb680e5fe0c8",
+                any: "{67e55044-10b1-426f-9247-bb680e5fe0c8",
+                inner: &["67e55044-10b1-426f-9247"],
+            },
+            Test {
+                field: "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+                any: "urn:67e55044-10b1-426f-9247-bb680e5fe0c8",
+                inner: &["67e55044-10b1-426f-9247-bb680e5fe0cg"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(bits(min = 1, max = 12))]
+    field: u16,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use garde::bounded::{BoundedString, BoundedVec};
+
+fn main() {
+    let _ = BoundedString::<5, 3>::new(String::new());
+    let _ = BoundedVec::<u8, 5, 3>::new(Vec::new());
+}
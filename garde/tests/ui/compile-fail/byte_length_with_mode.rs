@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(byte_length(chars, min = 1, max = 10))]
+    field: &'a str,
+}
+
+fn main() {}
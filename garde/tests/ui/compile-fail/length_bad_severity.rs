@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, severity = "info"))]
+    field: &'a str,
+}
+
+fn main() {}
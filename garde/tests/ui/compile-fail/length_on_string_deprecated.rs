@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    bare: &'a str,
+    #[garde(length(min = 1))]
+    owned: Option<String>,
+    #[garde(byte_length(min = 1), char_length(max = 8), length(simple, max = 8))]
+    explicit: &'a str,
+    #[garde(length(min = 1))]
+    vec: Vec<u8>,
+}
+
+fn main() {}
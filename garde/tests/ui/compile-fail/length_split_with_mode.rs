@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(split = ",", chars, min = 1))]
+    field: &'a str,
+}
+
+fn main() {}
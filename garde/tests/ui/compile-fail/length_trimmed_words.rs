@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(trimmed, words, min = 1))]
+    field: &'a str,
+}
+
+fn main() {}
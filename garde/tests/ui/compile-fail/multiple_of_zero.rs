@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(multiple_of(0))]
+    field: u64,
+}
+
+fn main() {}
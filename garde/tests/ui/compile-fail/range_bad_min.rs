@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(min = 10, max = -10))]
+    field: i32,
+    #[garde(range(min = 1.5, max = 0.5))]
+    float: f32,
+    #[garde(char_range(min = 'F', max = 'A'))]
+    grade: char,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+#[garde(rename_all = "camel_case")]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+fn main() {}
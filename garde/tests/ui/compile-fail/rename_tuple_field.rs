@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a>(#[garde(rename = "field", length(min = 1))] &'a str);
+
+fn main() {}
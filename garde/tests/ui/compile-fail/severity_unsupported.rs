@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+fn check(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii(severity = "warning"))]
+    a: &'a str,
+    #[garde(custom(check, severity = "warning"))]
+    b: &'a str,
+    #[garde(custom(check), severity = "warning")]
+    c: &'a str,
+}
+
+fn main() {}
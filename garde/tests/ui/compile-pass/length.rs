@@ -25,6 +25,10 @@ struct Test<'a> {
     graphemes: &'a str,
     #[garde(length(utf16, equal = 1))]
     utf16: &'a str,
+    #[garde(length(words, equal = 1))]
+    words: &'a str,
+    #[garde(length(lines, equal = 1))]
+    lines: &'a str,
 }
 
 fn main() {}
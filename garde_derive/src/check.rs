@@ -160,12 +160,14 @@ fn is_unary_struct(k: &model::ValidateKind) -> bool {
 fn get_options(attrs: &[(Span, model::Attr)]) -> model::Options {
     let mut options = model::Options {
         allow_unvalidated: false,
+        rename_all: None,
     };
 
     for (_, attr) in attrs {
         match attr {
             model::Attr::Context(..) => {}
             model::Attr::AllowUnvalidated => options.allow_unvalidated = true,
+            model::Attr::RenameAll(rule) => options.rename_all = Some(*rule),
             _ => {}
         }
     }
@@ -183,13 +185,16 @@ fn check_variant(
         model::Variant::Struct(map) => {
             let mut fields = Vec::new();
             for (ident, field) in map {
-                let field = match check_field(field, options) {
+                let mut field = match check_field(field, options) {
                     Ok(v) => v,
                     Err(e) => {
                         error.maybe_fold(e);
                         continue;
                     }
                 };
+                if let (None, Some(rule)) = (&field.alias, options.rename_all) {
+                    field.alias = Some(rule.apply(&ident.to_string()));
+                }
                 fields.push((ident, field))
             }
             model::ValidateVariant::Struct(fields)
@@ -197,6 +202,14 @@ fn check_variant(
         model::Variant::Tuple(list) => {
             let mut fields = Vec::new();
             for field in list {
+                for rule in &field.rules {
+                    if let model::RawRuleKind::Rename(..) = rule.kind {
+                        error.maybe_fold(syn::Error::new(
+                            rule.span,
+                            "`rename` is only supported on named fields",
+                        ));
+                    }
+                }
                 let field = match check_field(field, options) {
                     Ok(v) => v,
                     Err(e) => {
@@ -229,11 +242,13 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         ty,
         adapter: None,
         skip: None,
+        skip_if: None,
         alias: None,
         // message: None,
         code: None,
         dive: None,
         rule_set: model::RuleSet::empty(),
+        string_length: None,
     };
 
     if raw_rules.is_empty() {
@@ -264,6 +279,20 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     }
 
+    if let Some(expr) = &field.skip_if {
+        if field.skip.is_some() {
+            error.maybe_fold(syn::Error::new(
+                expr.span(),
+                "`skip_if` may not be combined with `skip`",
+            ))
+        } else if field.is_empty() {
+            error.maybe_fold(syn::Error::new(
+                expr.span(),
+                "`skip_if` requires at least one other rule",
+            ))
+        }
+    }
+
     if let Some(span) = field.dive {
         if field.rule_set.inner.is_some() {
             error.maybe_fold(syn::Error::new(
@@ -322,8 +351,8 @@ fn check_rule(
             }
         }};
 
-        ($rule:ident($($inner:expr)?), $span:expr) => {{
-            let rule = model::ValidateRule::$rule$(($inner))?;
+        ($rule:ident($($($inner:expr),+)?), $span:expr) => {{
+            let rule = model::ValidateRule::$rule$(($($inner),+))?;
             let name = rule.name();
             if !rule_set.rules.insert(rule) {
                 return Err(syn::Error::new($span, format!("duplicate rule `{name}`")));
@@ -335,6 +364,7 @@ fn check_rule(
     use model::RawRuleKind::*;
     match raw_rule.kind {
         Skip => apply!(skip = span, span),
+        SkipIf(expr) => apply!(skip_if = expr, span),
         Adapt(path) => apply!(adapter = path, span),
         Rename(alias) => apply!(alias = alias.value, span),
         // Message(message) => apply!(message = message, span),
@@ -342,30 +372,152 @@ fn check_rule(
         Dive => apply!(dive = span, span),
         Custom(custom) => rule_set.custom_rules.push(custom),
         Required => apply!(Required(), span),
+        NonEmpty => apply!(NonEmpty(), span),
         Ascii => apply!(Ascii(), span),
         Alphanumeric => apply!(Alphanumeric(), span),
+        AsciiAlphanumeric => apply!(AsciiAlphanumeric(), span),
+        Lowercase => apply!(Lowercase(), span),
+        Uppercase => apply!(Uppercase(), span),
         Email => apply!(Email(), span),
         Url => apply!(Url(), span),
         Ip => apply!(Ip(), span),
         IpV4 => apply!(IpV4(), span),
         IpV6 => apply!(IpV6(), span),
+        Hex => apply!(Hex(), span),
+        HexColor => apply!(HexColor(), span),
         CreditCard => apply!(CreditCard(), span),
         PhoneNumber => apply!(PhoneNumber(), span),
-        Length(v) => {
+        Phone => apply!(Phone(), span),
+        DateTime => apply!(DateTime(), span),
+        Uuid => apply!(Uuid(), span),
+        UuidAny => apply!(UuidAny(), span),
+        NoTrailingWhitespace => apply!(NoTrailingWhitespace(), span),
+        FinalNewline => apply!(FinalNewline(), span),
+        Length(mut v) => {
+            let (message, code, severity) = (
+                v.range.message.take(),
+                v.range.code.take(),
+                v.range.severity.take(),
+            );
             let range = check_range_generic(v.range)?;
-            match v.mode {
-                LengthMode::Simple => apply!(LengthSimple(range), span),
-                LengthMode::Bytes => apply!(LengthBytes(range), span),
-                LengthMode::Chars => apply!(LengthChars(range), span),
-                LengthMode::Graphemes => apply!(LengthGraphemes(range), span),
-                LengthMode::Utf16 => apply!(LengthUtf16(range), span),
+            let overrides = check_overrides(message, code, severity, &range)?;
+            let is_bare = v.mode.is_none() && !v.trimmed && v.split.is_none();
+            if !is_inner && is_bare && is_string_type(&field.ty) {
+                field.string_length = Some(span);
+            }
+            if let Some(delimiter) = v.split {
+                if v.mode.is_some() || v.trimmed {
+                    return Err(syn::Error::new(
+                        span,
+                        "`split` may not be combined with a length mode or `trimmed`",
+                    ));
+                }
+                apply!(LengthSplit(delimiter, range, overrides), span);
+                return Ok(());
+            }
+            if v.trimmed {
+                let mode = match v.mode.unwrap_or(LengthMode::Bytes) {
+                    mode @ (LengthMode::Bytes
+                    | LengthMode::Chars
+                    | LengthMode::Graphemes
+                    | LengthMode::Utf16) => mode,
+                    _ => {
+                        return Err(syn::Error::new(
+                            span,
+                            "`trimmed` may only be combined with `bytes`, `chars`, `graphemes` or `utf16`",
+                        ))
+                    }
+                };
+                apply!(LengthTrimmed(mode, range, overrides), span);
+                return Ok(());
+            }
+            match v.mode.unwrap_or_default() {
+                LengthMode::Simple => apply!(LengthSimple(range, overrides), span),
+                LengthMode::Bytes => apply!(LengthBytes(range, overrides), span),
+                LengthMode::Chars => apply!(LengthChars(range, overrides), span),
+                LengthMode::Graphemes => apply!(LengthGraphemes(range, overrides), span),
+                LengthMode::Utf16 => apply!(LengthUtf16(range, overrides), span),
+                LengthMode::Words => apply!(LengthWords(range, overrides), span),
+                LengthMode::Lines => apply!(LengthLines(range, overrides), span),
+                LengthMode::Count => apply!(LengthCount(range, overrides), span),
+                LengthMode::Base64Decoded => {
+                    apply!(LengthBase64Decoded(range, overrides), span)
+                }
             }
         }
         Matches(path) => apply!(Matches(path), span),
-        Range(v) => apply!(Range(check_range_not_ord(v)?), span),
+        Range(mut v) => {
+            let (message, code, severity) = (v.message.take(), v.code.take(), v.severity.take());
+            check_literal_order(&v)?;
+            let range = check_range_not_ord(v)?;
+            let overrides = check_overrides(message, code, severity, &range)?;
+            apply!(Range(range, overrides), span)
+        }
+        CharRange(mut v) => {
+            let (message, code, severity) = (v.message.take(), v.code.take(), v.severity.take());
+            check_literal_order(&v)?;
+            let range = check_range_not_ord(v)?;
+            let overrides = check_overrides(message, code, severity, &range)?;
+            apply!(CharRange(range, overrides), span)
+        }
+        Bits(mut v) => {
+            let (message, code, severity) = (v.message.take(), v.code.take(), v.severity.take());
+            let (None, Some(max), None) = (v.min, v.max, v.equal) else {
+                return Err(syn::Error::new(
+                    v.span,
+                    "`bits` requires `max`, and accepts no other bounds",
+                ));
+            };
+            let range = model::ValidateRange::LowerThan(max);
+            let overrides = check_overrides(message, code, severity, &range)?;
+            apply!(Bits(range, overrides), span)
+        }
+        MultipleOf(v) => {
+            if let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            }) = &v
+            {
+                if lit.base10_digits().bytes().all(|b| b == b'0') {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`multiple_of` divisor may not be zero",
+                    ));
+                }
+            }
+            apply!(MultipleOf(v), span)
+        }
         Contains(v) => apply!(Contains(v), span),
+        NotContains(v) => apply!(NotContains(v), span),
         Prefix(v) => apply!(Prefix(v), span),
         Suffix(v) => apply!(Suffix(v), span),
+        OneOf(v) => {
+            if v.contents.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`one_of` requires at least one value",
+                ));
+            }
+            apply!(OneOf(v.contents), span)
+        }
+        PrefixAny(v) => {
+            if v.contents.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`prefix_any` requires at least one prefix",
+                ));
+            }
+            apply!(PrefixAny(v.contents), span)
+        }
+        SuffixAny(v) => {
+            if v.contents.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`suffix_any` requires at least one suffix",
+                ));
+            }
+            apply!(SuffixAny(v.contents), span)
+        }
         Pattern(v) => apply!(Pattern(check_regex(v)?), span),
         Inner(v) => {
             if rule_set.inner.is_none() {
@@ -383,6 +535,21 @@ fn check_rule(
                 return Err(error);
             }
         }
+        Keys(v) => {
+            if rule_set.keys.is_none() {
+                rule_set.keys = Some(Box::new(model::RuleSet::empty()));
+            }
+
+            let mut error = None;
+            for raw_rule in v.contents {
+                if let Err(e) = check_rule(field, raw_rule, rule_set.keys.as_mut().unwrap(), true) {
+                    error.maybe_fold(e);
+                }
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
     };
 
     Ok(())
@@ -417,6 +584,9 @@ where
                     min: Some(min),
                     max: Some(max),
                     equal: None,
+                    message: None,
+                    code: None,
+                    severity: None,
                 })?,
                 model::Either::Left
             )
@@ -428,6 +598,9 @@ where
                     min: Some(min),
                     max: None,
                     equal: None,
+                    message: None,
+                    code: None,
+                    severity: None,
                 })?,
                 model::Either::Left
             )
@@ -439,6 +612,9 @@ where
                     min: None,
                     max: Some(max),
                     equal: None,
+                    message: None,
+                    code: None,
+                    severity: None,
                 })?,
                 model::Either::Left
             )
@@ -450,6 +626,9 @@ where
                     min: None,
                     max: None,
                     equal: Some(equal),
+                    message: None,
+                    code: None,
+                    severity: None,
                 })?,
                 model::Either::Left
             )
@@ -459,6 +638,9 @@ where
             min,
             max,
             equal,
+            message: None,
+            code: None,
+            severity: None,
         })?,
     };
 
@@ -495,6 +677,54 @@ where
     }
 }
 
+/// Bounds which are expressions can only be compared once they are evaluated,
+/// but literal bounds are compared here so that reversed bounds fail to compile.
+fn check_literal_order(range: &model::Range<syn::Expr>) -> syn::Result<()> {
+    enum Literal {
+        Int(i128),
+        Float(f64),
+        Char(char),
+    }
+
+    fn literal(expr: &syn::Expr) -> Option<Literal> {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+                syn::Lit::Int(v) => v.base10_parse().ok().map(Literal::Int),
+                syn::Lit::Float(v) => v.base10_parse().ok().map(Literal::Float),
+                syn::Lit::Char(v) => Some(Literal::Char(v.value())),
+                _ => None,
+            },
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => match literal(expr)? {
+                Literal::Int(v) => Some(Literal::Int(-v)),
+                Literal::Float(v) => Some(Literal::Float(-v)),
+                Literal::Char(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    let (Some(min), Some(max)) = (&range.min, &range.max) else {
+        return Ok(());
+    };
+    let reversed = match (literal(min), literal(max)) {
+        (Some(Literal::Int(min)), Some(Literal::Int(max))) => min > max,
+        (Some(Literal::Float(min)), Some(Literal::Float(max))) => min > max,
+        (Some(Literal::Char(min)), Some(Literal::Char(max))) => min > max,
+        _ => false,
+    };
+    if reversed {
+        return Err(syn::Error::new(
+            min.span(),
+            "`min` must be lower than or equal to `max`",
+        ));
+    }
+    Ok(())
+}
+
 fn check_range_not_ord<T>(range: model::Range<T>) -> syn::Result<model::ValidateRange<T>> {
     if let Some(equal) = range.equal {
         return if range.min.is_some() || range.max.is_some() {
@@ -518,6 +748,106 @@ fn check_range_not_ord<T>(range: model::Range<T>) -> syn::Result<model::Validate
     }
 }
 
+/// Whether `ty` is written as `String` or `str`, optionally behind references or an `Option`.
+///
+/// This only looks at the syntax, so aliases and other string types are not detected.
+fn is_string_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => is_string_type(&reference.elem),
+        syn::Type::Paren(paren) => is_string_type(&paren.elem),
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let Some(last) = path.path.segments.last() else {
+                return false;
+            };
+            match &last.arguments {
+                syn::PathArguments::None => last.ident == "String" || last.ident == "str",
+                syn::PathArguments::AngleBracketed(args) if last.ident == "Option" => {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) if args.args.len() == 1 => {
+                            is_string_type(ty)
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn check_overrides<T>(
+    message: Option<model::Str>,
+    code: Option<model::Str>,
+    severity: Option<model::Str>,
+    range: &model::ValidateRange<T>,
+) -> syn::Result<model::ErrorOverrides> {
+    let warning = match severity {
+        None => false,
+        Some(severity) => match severity.value.as_str() {
+            "error" => false,
+            "warning" => true,
+            _ => {
+                return Err(syn::Error::new(
+                    severity.span,
+                    "expected `\"error\"` or `\"warning\"`",
+                ))
+            }
+        },
+    };
+    Ok(model::ErrorOverrides {
+        message: message.map(|m| check_message(m, range)).transpose()?,
+        code: code.map(|code| code.value),
+        warning,
+    })
+}
+
+fn check_message<T>(
+    message: model::Str,
+    range: &model::ValidateRange<T>,
+) -> syn::Result<model::ValidateMessage> {
+    let mut uses_min = false;
+    let mut uses_max = false;
+
+    let mut rest = message.value.as_str();
+    while let Some(i) = rest.find(['{', '}']) {
+        let tail = &rest[i..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            rest = &tail[2..];
+        } else if let Some(tail) = tail.strip_prefix("{min}") {
+            uses_min = true;
+            rest = tail;
+        } else if let Some(tail) = tail.strip_prefix("{max}") {
+            uses_max = true;
+            rest = tail;
+        } else {
+            return Err(syn::Error::new(
+                message.span,
+                "invalid message, only `{min}` and `{max}` may be interpolated, use `{{` and `}}` to escape braces",
+            ));
+        }
+    }
+
+    if uses_min && range.min().is_none() {
+        return Err(syn::Error::new(
+            message.span,
+            "message refers to `{min}`, but no `min` was specified",
+        ));
+    }
+    if uses_max && range.max().is_none() {
+        return Err(syn::Error::new(
+            message.span,
+            "message refers to `{max}`, but no `max` was specified",
+        ));
+    }
+
+    Ok(model::ValidateMessage {
+        format: message.value,
+        uses_min,
+        uses_max,
+    })
+}
+
 fn check_regex(value: model::Pattern) -> syn::Result<model::ValidatePattern> {
     match value {
         model::Pattern::Lit(lit) => {
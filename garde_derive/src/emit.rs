@@ -20,11 +20,24 @@ impl ToTokens for model::Validate {
             is_transparent: self.is_transparent,
             kind: &self.kind,
         };
+        let report_capacity = match &self.kind {
+            model::ValidateKind::Struct(variant) => variant.num_validated_fields(),
+            model::ValidateKind::Enum(variants) => variants
+                .iter()
+                .filter_map(|(_, variant)| variant.as_ref())
+                .map(model::ValidateVariant::num_validated_fields)
+                .max()
+                .unwrap_or(0),
+        };
 
         quote! {
             impl #impl_generics ::garde::Validate for #ident #ty_generics #where_clause {
                 type Context = #context_ty ;
 
+                fn report_capacity(&self) -> usize {
+                    #report_capacity
+                }
+
                 #[allow(clippy::needless_borrow)]
                 fn validate_into(
                     &self,
@@ -125,9 +138,10 @@ struct Struct<'a> {
 impl<'a> ToTokens for Struct<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .map(|(key, field)| (Binding::Ident(key), field, key.to_string())),
+            self.fields.iter().map(|(key, field)| {
+                let name = field.alias.clone().unwrap_or_else(|| key.to_string());
+                (Binding::Ident(key), field, name)
+            }),
             |key, value| match self.is_transparent {
                 true => quote! {{
                     #value
@@ -180,38 +194,45 @@ impl<'a> ToTokens for Inner<'a> {
             rule_set,
         } = self;
 
-        let outer = match rule_set.has_top_level_rules() {
-            true => {
-                let rules = Rules {
-                    rules_mod,
-                    rule_set,
-                };
-                Some(quote! {#rules})
-            }
-            false => None,
+        let Some(value) = nested_rules(rules_mod, rule_set) else {
+            return;
         };
-        let inner = rule_set.inner.as_deref().map(|rule_set| Inner {
+
+        quote! {
+            #rules_mod::inner::apply(
+                &*__garde_binding,
+                |__garde_binding, __garde_inner_key| {
+                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_inner_key);
+                    #value
+                }
+            );
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct Keys<'a> {
+    rules_mod: &'a TokenStream2,
+    rule_set: &'a model::RuleSet,
+}
+
+impl<'a> ToTokens for Keys<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Keys {
             rules_mod,
             rule_set,
-        });
+        } = self;
 
-        let value = match (outer, inner) {
-            (Some(outer), Some(inner)) => quote! {
-                #outer
-                #inner
-            },
-            (None, Some(inner)) => quote! {
-                #inner
-            },
-            (Some(outer), None) => outer,
-            (None, None) => return,
+        let Some(value) = nested_rules(rules_mod, rule_set) else {
+            return;
         };
 
         quote! {
-            #rules_mod::inner::apply(
+            #rules_mod::keys::apply(
                 &*__garde_binding,
-                |__garde_binding, __garde_inner_key| {
-                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_inner_key);
+                |__garde_binding, __garde_map_key| {
+                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_map_key);
+                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, "key");
                     #value
                 }
             );
@@ -220,6 +241,38 @@ impl<'a> ToTokens for Inner<'a> {
     }
 }
 
+/// The rules applied to each item of an `inner` or `keys` rule.
+fn nested_rules(rules_mod: &TokenStream2, rule_set: &model::RuleSet) -> Option<TokenStream2> {
+    let outer = match rule_set.has_top_level_rules() {
+        true => {
+            let rules = Rules {
+                rules_mod,
+                rule_set,
+            };
+            Some(quote! {#rules})
+        }
+        false => None,
+    };
+    let inner = rule_set.inner.as_deref().map(|rule_set| Inner {
+        rules_mod,
+        rule_set,
+    });
+    let keys = rule_set.keys.as_deref().map(|rule_set| Keys {
+        rules_mod,
+        rule_set,
+    });
+
+    if outer.is_none() && inner.is_none() && keys.is_none() {
+        return None;
+    }
+
+    Some(quote! {
+        #outer
+        #inner
+        #keys
+    })
+}
+
 struct Rules<'a> {
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
@@ -249,6 +302,9 @@ impl<'a> ToTokens for Rules<'a> {
 
         for custom_rule in rule_set.custom_rules.iter() {
             quote! {
+                if __garde_report.__is_done() {
+                    return;
+                }
                 if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
                     __garde_report.append(__garde_path(), __garde_error);
                 }
@@ -260,7 +316,8 @@ impl<'a> ToTokens for Rules<'a> {
             let name = TokenStream2::from_str(rule.name()).unwrap();
             use model::ValidateRule::*;
             let args = match rule {
-                Ascii | Alphanumeric | Email | Url | CreditCard | PhoneNumber | Required => {
+                Ascii | Alphanumeric | AsciiAlphanumeric | Lowercase | Uppercase | Email | Url
+                | CreditCard | PhoneNumber | Phone | DateTime | Required | NonEmpty => {
                     quote!(())
                 }
                 Ip => {
@@ -272,36 +329,68 @@ impl<'a> ToTokens for Rules<'a> {
                 IpV6 => {
                     quote!((#rules_mod::ip::IpKind::V6,))
                 }
-                LengthSimple(range)
-                | LengthBytes(range)
-                | LengthChars(range)
-                | LengthGraphemes(range)
-                | LengthUtf16(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => {
-                        quote!((#min, usize::MAX))
-                    }
-                    model::ValidateRange::LowerThan(max) => {
-                        quote!((0usize, #max))
-                    }
-                    model::ValidateRange::Between(min, max) => {
-                        quote!((#min, #max))
-                    }
-                    model::ValidateRange::Equal(equal) => {
-                        quote!((#equal, #equal))
-                    }
-                },
+                Uuid => {
+                    quote!((#rules_mod::uuid::UuidFormat::Hyphenated,))
+                }
+                UuidAny => {
+                    quote!((#rules_mod::uuid::UuidFormat::Any,))
+                }
+                Hex => {
+                    quote!((#rules_mod::hex::HexKind::String,))
+                }
+                HexColor => {
+                    quote!((#rules_mod::hex::HexKind::Color,))
+                }
+                NoTrailingWhitespace => {
+                    quote!((#rules_mod::text_hygiene::TextHygieneKind::NoTrailingWhitespace,))
+                }
+                FinalNewline => {
+                    quote!((#rules_mod::text_hygiene::TextHygieneKind::FinalNewline,))
+                }
+                LengthSimple(range, _)
+                | LengthBytes(range, _)
+                | LengthChars(range, _)
+                | LengthGraphemes(range, _)
+                | LengthUtf16(range, _)
+                | LengthWords(range, _)
+                | LengthLines(range, _)
+                | LengthCount(range, _)
+                | LengthBase64Decoded(range, _) => LengthBounds(range).into_token_stream(),
+                LengthTrimmed(mode, range, _) => {
+                    let mode = match mode {
+                        model::LengthMode::Bytes => quote!(Bytes),
+                        model::LengthMode::Chars => quote!(Chars),
+                        model::LengthMode::Graphemes => quote!(Graphemes),
+                        model::LengthMode::Utf16 => quote!(Utf16),
+                        _ => unreachable!("`trimmed` mode should already be checked"),
+                    };
+                    let bounds = LengthBounds(range);
+                    quote!((#rules_mod::length::LengthMode::#mode, #bounds))
+                }
+                LengthSplit(delimiter, range, _) => {
+                    let bounds = LengthBounds(range);
+                    quote_spanned!(delimiter.span() => (#delimiter, #bounds))
+                }
                 Matches(path) => {
                     quote!((stringify!(#path), &self.#path))
                 }
-                Range(range) => match range {
+                Range(range, _) | CharRange(range, _) => match range {
                     model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
                     model::ValidateRange::LowerThan(max) => quote!((None, Some(#max))),
                     model::ValidateRange::Between(min, max) => quote!((Some(#min), Some(#max))),
                     model::ValidateRange::Equal(equal) => quote!((Some(#equal), Some(#equal))),
                 },
-                Contains(expr) | Prefix(expr) | Suffix(expr) => {
+                Bits(range, _) => match range {
+                    model::ValidateRange::LowerThan(max) => quote!((#max,)),
+                    _ => unreachable!("`bits` should only have a `max`"),
+                },
+                MultipleOf(expr) => quote_spanned!(expr.span() => (#expr,)),
+                Contains(expr) | NotContains(expr) | Prefix(expr) | Suffix(expr) => {
                     quote_spanned!(expr.span() => (&#expr,))
                 }
+                OneOf(values) | PrefixAny(values) | SuffixAny(values) => {
+                    quote!((&[#(#values),*] as &[&str],))
+                }
                 Pattern(pat) => match pat {
                     model::ValidatePattern::Expr(expr) => quote_spanned!(expr.span() => (&#expr,)),
                     #[cfg(feature = "regex")]
@@ -327,8 +416,60 @@ impl<'a> ToTokens for Rules<'a> {
                 },
             };
 
+            let (message, code, warning) = match rule {
+                LengthSimple(range, overrides)
+                | LengthBytes(range, overrides)
+                | LengthChars(range, overrides)
+                | LengthGraphemes(range, overrides)
+                | LengthUtf16(range, overrides)
+                | LengthWords(range, overrides)
+                | LengthLines(range, overrides)
+                | LengthCount(range, overrides)
+                | LengthBase64Decoded(range, overrides)
+                | LengthTrimmed(_, range, overrides)
+                | LengthSplit(_, range, overrides) => (
+                    overrides
+                        .message
+                        .as_ref()
+                        .map(|message| Message { message, range }.into_token_stream()),
+                    overrides.code.as_ref(),
+                    overrides.warning,
+                ),
+                Range(range, overrides) | CharRange(range, overrides) | Bits(range, overrides) => (
+                    overrides
+                        .message
+                        .as_ref()
+                        .map(|message| Message { message, range }.into_token_stream()),
+                    overrides.code.as_ref(),
+                    overrides.warning,
+                ),
+                _ => (None, None, false),
+            };
+            let message = message.map(|message| {
+                quote! {
+                    let __garde_error = __garde_error.with_message(#message);
+                }
+            });
+            let code = code.map(|code| {
+                quote! {
+                    let __garde_error = __garde_error.with_code(#code);
+                }
+            });
+            let severity = warning.then(|| {
+                quote! {
+                    let __garde_error =
+                        __garde_error.with_severity(::garde::error::Severity::Warning);
+                }
+            });
+
             quote! {
+                if __garde_report.__is_done() {
+                    return;
+                }
                 if let Err(__garde_error) = (#rules_mod::#name::apply)(&*__garde_binding, #args) {
+                    #message
+                    #code
+                    #severity
                     __garde_report.append(__garde_path(), __garde_error);
                 }
             }
@@ -337,6 +478,58 @@ impl<'a> ToTokens for Rules<'a> {
     }
 }
 
+struct LengthBounds<'a>(&'a model::ValidateRange<model::Either<usize, syn::Expr>>);
+
+impl<'a> ToTokens for LengthBounds<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self.0 {
+            model::ValidateRange::GreaterThan(min) => {
+                quote!((#min, usize::MAX))
+            }
+            model::ValidateRange::LowerThan(max) => {
+                quote!((0usize, #max))
+            }
+            model::ValidateRange::Between(min, max) => {
+                quote!((#min, #max))
+            }
+            model::ValidateRange::Equal(equal) => {
+                quote!((#equal, #equal))
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct Message<'a, T> {
+    message: &'a model::ValidateMessage,
+    range: &'a model::ValidateRange<T>,
+}
+
+impl<'a, T: ToTokens> ToTokens for Message<'a, T> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let model::ValidateMessage {
+            format,
+            uses_min,
+            uses_max,
+        } = self.message;
+        let min = self
+            .range
+            .min()
+            .filter(|_| *uses_min)
+            .map(|min| quote!(min = #min,));
+        let max = self
+            .range
+            .max()
+            .filter(|_| *uses_max)
+            .map(|max| quote!(max = #max,));
+
+        quote! {
+            ::garde::external::compact_str::format_compact!(#format, #min #max)
+        }
+        .to_tokens(tokens)
+    }
+}
+
 struct Fields<I, F>(RefCell<Option<I>>, F);
 
 impl<I, F> Fields<I, F> {
@@ -396,21 +589,41 @@ where
                 _ => unreachable!("`dive` and `inner` are mutually exclusive"),
             };
 
-            let value = match (outer, inner) {
-                (Some(outer), Some(inner)) => quote! {
-                    let __garde_binding = &*#binding;
-                    #inner
-                    #outer
-                },
-                (None, Some(inner)) => quote! {
-                    let __garde_binding = &*#binding;
-                    #inner
-                },
-                (Some(outer), None) => quote! {
-                    let __garde_binding = &*#binding;
-                    #outer
+            let keys = field.rule_set.keys.as_deref().map(|rule_set| Keys {
+                rules_mod,
+                rule_set,
+            });
+
+            if outer.is_none() && inner.is_none() && keys.is_none() {
+                unreachable!("field should already be skipped");
+            }
+
+            // `length` on a string counts bytes, which is easily mistaken for chars
+            let string_length = field.string_length.map(|span| {
+                quote_spanned! {span=>
+                    #[deprecated(
+                        note = "`length` on a string counts bytes, use `byte_length` or `char_length` instead"
+                    )]
+                    fn length() {}
+                    length();
+                }
+            });
+
+            let value = quote! {
+                let __garde_binding = &*#binding;
+                #string_length
+                #inner
+                #keys
+                #outer
+            };
+
+            let value = match &field.skip_if {
+                Some(skip_if) => quote! {
+                    if !(#skip_if)(self, __garde_user_ctx) {
+                        #value
+                    }
                 },
-                (None, None) => unreachable!("field should already be skipped"),
+                None => value,
             };
 
             let add = &self.1;
@@ -15,6 +15,7 @@ pub enum Attr {
     Context(Box<Type>, Ident),
     AllowUnvalidated,
     Transparent,
+    RenameAll(RenameRule),
 }
 
 impl Attr {
@@ -31,6 +32,72 @@ impl Attr {
             Attr::Context(..) => "context",
             Attr::AllowUnvalidated => "allow_unvalidated",
             Attr::Transparent => "transparent",
+            Attr::RenameAll(..) => "rename_all",
+        }
+    }
+}
+
+/// The casing of `#[garde(rename_all = "..")]`, which is applied to the `snake_case` field names.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    pub const NAMES: &'static [&'static str] = &[
+        "lowercase",
+        "UPPERCASE",
+        "PascalCase",
+        "camelCase",
+        "snake_case",
+        "SCREAMING_SNAKE_CASE",
+        "kebab-case",
+        "SCREAMING-KEBAB-CASE",
+    ];
+
+    pub fn from_str(name: &str) -> Option<Self> {
+        Some(match name {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    pub fn apply(self, field: &str) -> String {
+        let field = field.strip_prefix("r#").unwrap_or(field);
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => {
+                let mut out = String::with_capacity(field.len());
+                let mut capitalize = matches!(self, Self::Pascal);
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        out.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        out.push(c);
+                    }
+                }
+                out
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.replace('_', "-").to_ascii_uppercase(),
         }
     }
 }
@@ -74,34 +141,57 @@ pub struct RawRule {
 
 pub enum RawRuleKind {
     Skip,
+    SkipIf(Expr),
     Adapt(Path),
     Rename(Str),
     // Message(Message),
     Code(Str),
     Dive,
     Required,
+    NonEmpty,
     Ascii,
     Alphanumeric,
+    AsciiAlphanumeric,
+    Lowercase,
+    Uppercase,
     Email,
     Url,
     Ip,
     IpV4,
     IpV6,
+    Hex,
+    HexColor,
     CreditCard,
     PhoneNumber,
+    Phone,
+    DateTime,
+    Uuid,
+    UuidAny,
+    NoTrailingWhitespace,
+    FinalNewline,
     Length(RawLength),
     Matches(Path),
     Range(Range<Expr>),
+    CharRange(Range<Expr>),
+    Bits(Range<Expr>),
+    MultipleOf(Expr),
     Contains(Expr),
+    NotContains(Expr),
     Prefix(Expr),
     Suffix(Expr),
+    OneOf(List<Expr>),
+    PrefixAny(List<Expr>),
+    SuffixAny(List<Expr>),
     Pattern(Pattern),
     Custom(Expr),
     Inner(List<RawRule>),
+    Keys(List<RawRule>),
 }
 
 pub struct RawLength {
-    pub mode: LengthMode,
+    pub mode: Option<LengthMode>,
+    pub trimmed: bool,
+    pub split: Option<Expr>,
     pub range: Range<Either<usize, Expr>>,
 }
 
@@ -113,6 +203,10 @@ pub enum LengthMode {
     Chars,
     Graphemes,
     Utf16,
+    Words,
+    Lines,
+    Count,
+    Base64Decoded,
 }
 
 pub enum Either<L, R> {
@@ -148,6 +242,9 @@ pub struct Range<T> {
     pub min: Option<T>,
     pub max: Option<T>,
     pub equal: Option<T>,
+    pub message: Option<Str>,
+    pub code: Option<Str>,
+    pub severity: Option<Str>,
 }
 
 pub struct List<T> {
@@ -168,6 +265,7 @@ pub struct Validate {
 
 pub struct Options {
     pub allow_unvalidated: bool,
+    pub rename_all: Option<RenameRule>,
 }
 
 pub enum ValidateKind {
@@ -180,12 +278,16 @@ pub struct ValidateField {
 
     pub adapter: Option<Path>,
     pub skip: Option<Span>,
+    pub skip_if: Option<Expr>,
     pub alias: Option<String>,
     // pub message: Option<Message>,
     pub code: Option<String>,
 
     pub dive: Option<Span>,
     pub rule_set: RuleSet,
+
+    /// Span of a bare `length` rule, if the field is written as a string type.
+    pub string_length: Option<Span>,
 }
 
 impl ValidateField {
@@ -202,6 +304,7 @@ pub struct RuleSet {
     pub rules: BTreeSet<ValidateRule>,
     pub custom_rules: Vec<Expr>,
     pub inner: Option<Box<RuleSet>>,
+    pub keys: Option<Box<RuleSet>>,
 }
 
 impl RuleSet {
@@ -210,6 +313,7 @@ impl RuleSet {
             rules: BTreeSet::new(),
             custom_rules: Vec::new(),
             inner: None,
+            keys: None,
         }
     }
 
@@ -218,7 +322,11 @@ impl RuleSet {
             Some(inner) => inner.is_empty(),
             None => true,
         };
-        inner_empty && self.rules.is_empty() && self.custom_rules.is_empty()
+        let keys_empty = match &self.keys {
+            Some(keys) => keys.is_empty(),
+            None => true,
+        };
+        inner_empty && keys_empty && self.rules.is_empty() && self.custom_rules.is_empty()
     }
 
     pub fn has_top_level_rules(&self) -> bool {
@@ -229,25 +337,50 @@ impl RuleSet {
 #[repr(u8)]
 pub enum ValidateRule {
     Required,
+    NonEmpty,
     Ascii,
     Alphanumeric,
+    AsciiAlphanumeric,
+    Lowercase,
+    Uppercase,
     Email,
     Url,
     Ip,
     IpV4,
     IpV6,
+    Hex,
+    HexColor,
     CreditCard,
     PhoneNumber,
-    LengthSimple(LengthRange),
-    LengthBytes(LengthRange),
-    LengthChars(LengthRange),
-    LengthGraphemes(LengthRange),
-    LengthUtf16(LengthRange),
+    Phone,
+    DateTime,
+    Uuid,
+    UuidAny,
+    NoTrailingWhitespace,
+    FinalNewline,
+    LengthSimple(LengthRange, ErrorOverrides),
+    LengthBytes(LengthRange, ErrorOverrides),
+    LengthChars(LengthRange, ErrorOverrides),
+    LengthGraphemes(LengthRange, ErrorOverrides),
+    LengthUtf16(LengthRange, ErrorOverrides),
+    LengthWords(LengthRange, ErrorOverrides),
+    LengthLines(LengthRange, ErrorOverrides),
+    LengthCount(LengthRange, ErrorOverrides),
+    LengthBase64Decoded(LengthRange, ErrorOverrides),
+    LengthTrimmed(LengthMode, LengthRange, ErrorOverrides),
+    LengthSplit(Expr, LengthRange, ErrorOverrides),
     Matches(Path),
-    Range(ValidateRange<Expr>),
+    Range(ValidateRange<Expr>, ErrorOverrides),
+    CharRange(ValidateRange<Expr>, ErrorOverrides),
+    Bits(ValidateRange<Expr>, ErrorOverrides),
+    MultipleOf(Expr),
     Contains(Expr),
+    NotContains(Expr),
     Prefix(Expr),
     Suffix(Expr),
+    OneOf(Vec<Expr>),
+    PrefixAny(Vec<Expr>),
+    SuffixAny(Vec<Expr>),
     Pattern(ValidatePattern),
 }
 
@@ -257,25 +390,50 @@ impl ValidateRule {
     pub fn name(&self) -> &'static str {
         match self {
             ValidateRule::Required => "required",
+            ValidateRule::NonEmpty => "non_empty",
             ValidateRule::Ascii => "ascii",
             ValidateRule::Alphanumeric => "alphanumeric",
+            ValidateRule::AsciiAlphanumeric => "ascii_alphanumeric",
+            ValidateRule::Lowercase => "lowercase",
+            ValidateRule::Uppercase => "uppercase",
             ValidateRule::Email => "email",
             ValidateRule::Url => "url",
             ValidateRule::Ip => "ip",
             ValidateRule::IpV4 => "ip",
             ValidateRule::IpV6 => "ip",
+            ValidateRule::Hex => "hex",
+            ValidateRule::HexColor => "hex",
             ValidateRule::CreditCard => "credit_card",
             ValidateRule::PhoneNumber => "phone_number",
-            ValidateRule::LengthSimple(_) => "length::simple",
-            ValidateRule::LengthBytes(_) => "length::bytes",
-            ValidateRule::LengthChars(_) => "length::chars",
-            ValidateRule::LengthGraphemes(_) => "length::graphemes",
-            ValidateRule::LengthUtf16(_) => "length::utf16",
+            ValidateRule::Phone => "phone",
+            ValidateRule::DateTime => "datetime",
+            ValidateRule::Uuid => "uuid",
+            ValidateRule::UuidAny => "uuid",
+            ValidateRule::NoTrailingWhitespace => "text_hygiene",
+            ValidateRule::FinalNewline => "text_hygiene",
+            ValidateRule::LengthSimple(..) => "length::simple",
+            ValidateRule::LengthBytes(..) => "length::bytes",
+            ValidateRule::LengthChars(..) => "length::chars",
+            ValidateRule::LengthGraphemes(..) => "length::graphemes",
+            ValidateRule::LengthUtf16(..) => "length::utf16",
+            ValidateRule::LengthWords(..) => "length::words",
+            ValidateRule::LengthLines(..) => "length::lines",
+            ValidateRule::LengthCount(..) => "length::count",
+            ValidateRule::LengthBase64Decoded(..) => "length::base64_decoded",
+            ValidateRule::LengthTrimmed(..) => "length::trimmed",
+            ValidateRule::LengthSplit(..) => "length::split",
             ValidateRule::Matches(_) => "matches",
-            ValidateRule::Range(_) => "range",
+            ValidateRule::Range(..) => "range",
+            ValidateRule::CharRange(..) => "char_range",
+            ValidateRule::Bits(..) => "bits",
+            ValidateRule::MultipleOf(_) => "multiple_of",
             ValidateRule::Contains(_) => "contains",
+            ValidateRule::NotContains(_) => "not_contains",
             ValidateRule::Prefix(_) => "prefix",
             ValidateRule::Suffix(_) => "suffix",
+            ValidateRule::OneOf(_) => "one_of",
+            ValidateRule::PrefixAny(_) => "prefix_any",
+            ValidateRule::SuffixAny(_) => "suffix_any",
             ValidateRule::Pattern(_) => "pattern",
         }
     }
@@ -294,6 +452,43 @@ pub enum ValidateRange<T> {
     Equal(T),
 }
 
+impl<T> ValidateRange<T> {
+    pub fn min(&self) -> Option<&T> {
+        match self {
+            ValidateRange::GreaterThan(min) | ValidateRange::Between(min, _) => Some(min),
+            ValidateRange::Equal(equal) => Some(equal),
+            ValidateRange::LowerThan(_) => None,
+        }
+    }
+
+    pub fn max(&self) -> Option<&T> {
+        match self {
+            ValidateRange::LowerThan(max) | ValidateRange::Between(_, max) => Some(max),
+            ValidateRange::Equal(equal) => Some(equal),
+            ValidateRange::GreaterThan(_) => None,
+        }
+    }
+}
+
+/// Replacements for parts of the error produced by a rule.
+#[derive(Default)]
+pub struct ErrorOverrides {
+    pub message: Option<ValidateMessage>,
+    pub code: Option<String>,
+    /// Report the error as a warning, which does not fail validation.
+    pub warning: bool,
+}
+
+/// A custom message which replaces the default one produced by a rule.
+///
+/// `format` is a valid format string which may only refer to the
+/// named arguments `min` and `max`.
+pub struct ValidateMessage {
+    pub format: String,
+    pub uses_min: bool,
+    pub uses_max: bool,
+}
+
 pub enum ValidateVariant {
     Struct(Vec<(Ident, ValidateField)>),
     Tuple(Vec<ValidateField>),
@@ -303,6 +498,17 @@ impl ValidateVariant {
     pub fn empty() -> Self {
         Self::Tuple(Vec::new())
     }
+
+    pub fn num_validated_fields(&self) -> usize {
+        let fields: Vec<&ValidateField> = match self {
+            Self::Struct(fields) => fields.iter().map(|(_, field)| field).collect(),
+            Self::Tuple(fields) => fields.iter().collect(),
+        };
+        fields
+            .into_iter()
+            .filter(|field| field.skip.is_none() && !field.is_empty())
+            .count()
+    }
 }
 
 impl PartialEq for ValidateRule {
@@ -101,6 +101,21 @@ impl Parse for model::Attr {
             }
             "allow_unvalidated" => Ok(model::Attr::AllowUnvalidated),
             "transparent" => Ok(model::Attr::Transparent),
+            "rename_all" => {
+                let _ = input.parse::<Token![=]>()?;
+                let case = model::Str::parse(input)?;
+                match model::RenameRule::from_str(&case.value) {
+                    Some(rule) => Ok(model::Attr::RenameAll(rule)),
+                    None => Err(syn::Error::new(
+                        case.span,
+                        format!(
+                            "unrecognized case `{}`, expected one of {}",
+                            case.value,
+                            model::RenameRule::NAMES.join(", ")
+                        ),
+                    )),
+                }
+            }
             _ => Err(syn::Error::new(ident.span(), "unrecognized attribute")),
         }
     }
@@ -249,6 +264,69 @@ impl Parse for model::RawRule {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let ident = Ident::parse_any(input)?;
 
+        if ident == "skip_if" {
+            let _ = input.parse::<Token![=]>()?;
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::SkipIf(input.parse()?),
+            });
+        }
+
+        if ident == "rename" && input.peek(Token![=]) {
+            let _ = input.parse::<Token![=]>()?;
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::Rename(input.parse()?),
+            });
+        }
+
+        if ident == "byte_length" || ident == "char_length" {
+            let content;
+            syn::parenthesized!(content in input);
+            let mut length = content.parse::<model::RawLength>()?;
+            if length.mode.is_some() {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("`{ident}` may not be combined with a length mode"),
+                ));
+            }
+            length.mode = Some(match ident == "byte_length" {
+                true => model::LengthMode::Bytes,
+                false => model::LengthMode::Chars,
+            });
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::Length(length),
+            });
+        }
+
+        if ident == "severity" {
+            // consume the value, so the remaining rules are still parsed
+            if input.parse::<Option<Token![=]>>()?.is_some() {
+                let _ = input.parse::<syn::Expr>()?;
+            } else if input.peek(syn::token::Paren) {
+                let _ = input.parse::<proc_macro2::Group>()?;
+            }
+            return Err(syn::Error::new(
+                ident.span(),
+                "`severity` is not a rule, it is an argument of `length`, `range`, `char_range` and `bits`, \
+                 e.g. `length(max = 10, severity = \"warning\")`",
+            ));
+        }
+
+        if !matches!(
+            ident.to_string().as_str(),
+            "length" | "range" | "char_range" | "bits"
+        ) {
+            if let Some(span) = find_severity_arg(input) {
+                let _ = input.parse::<proc_macro2::Group>()?;
+                return Err(syn::Error::new(
+                    span,
+                    format!("`{ident}` does not accept `severity`, which is only supported by `length`, `range`, `char_range` and `bits`"),
+                ));
+            }
+        }
+
         macro_rules! rules {
             (($input:ident, $ident:ident) {
                 $($name:literal => $rule:ident $(($content:ident))?,)*
@@ -280,29 +358,74 @@ impl Parse for model::RawRule {
                 "code" => Code(content),
                 "dive" => Dive,
                 "required" => Required,
+                "non_empty" => NonEmpty,
                 "ascii" => Ascii,
                 "alphanumeric" => Alphanumeric,
+                "ascii_alphanumeric" => AsciiAlphanumeric,
+                "lowercase" => Lowercase,
+                "uppercase" => Uppercase,
                 "email" => Email,
                 "url" => Url,
                 "ip" => Ip,
                 "ipv4" => IpV4,
                 "ipv6" => IpV6,
+                "hex" => Hex,
+                "hex_color" => HexColor,
                 "credit_card" => CreditCard,
                 "phone_number" => PhoneNumber,
+                "phone" => Phone,
+                "datetime" => DateTime,
+                "uuid" => Uuid,
+                "uuid_any" => UuidAny,
+                "no_trailing_whitespace" => NoTrailingWhitespace,
+                "final_newline" => FinalNewline,
                 "length" => Length(content),
                 "matches" => Matches(content),
                 "range" => Range(content),
+                "char_range" => CharRange(content),
+                "bits" => Bits(content),
+                "multiple_of" => MultipleOf(content),
                 "contains" => Contains(content),
+                "not_contains" => NotContains(content),
                 "prefix" => Prefix(content),
                 "suffix" => Suffix(content),
+                "one_of" => OneOf(content),
+                "prefix_any" => PrefixAny(content),
+                "suffix_any" => SuffixAny(content),
                 "pattern" => Pattern(content),
                 "custom" => Custom(content),
                 "inner" => Inner(content),
+                "inner_values" => Inner(content),
+                "keys" => Keys(content),
+                "inner_keys" => Keys(content),
             }
         }
     }
 }
 
+/// Finds a top-level `severity = ..` argument in the parenthesized arguments of a rule.
+fn find_severity_arg(input: syn::parse::ParseStream) -> Option<Span> {
+    use proc_macro2::{Spacing, TokenTree};
+
+    let group = input.fork().parse::<proc_macro2::Group>().ok()?;
+    let mut tokens = group.stream().into_iter().peekable();
+    let mut at_argument_start = true;
+    while let Some(token) = tokens.next() {
+        match &token {
+            TokenTree::Ident(ident) if at_argument_start && ident == "severity" => {
+                if let Some(TokenTree::Punct(punct)) = tokens.peek() {
+                    if punct.as_char() == '=' && punct.spacing() == Spacing::Alone {
+                        return Some(ident.span());
+                    }
+                }
+            }
+            _ => {}
+        }
+        at_argument_start = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ',');
+    }
+    None
+}
+
 impl Parse for model::Pattern {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.peek(syn::Lit) {
@@ -345,6 +468,11 @@ impl Parse for model::RawLength {
         let mut min = None;
         let mut max = None;
         let mut equal = None;
+        let mut message = None;
+        let mut code = None;
+        let mut severity = None;
+        let mut trimmed = None;
+        let mut split = None;
 
         for arg in args {
             let arg = match arg {
@@ -383,6 +511,41 @@ impl Parse for model::RawLength {
                         mode = Some(v)
                     }
                 }
+                RawLengthArgument::Message(span, v) => {
+                    if message.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        message = Some(v)
+                    }
+                }
+                RawLengthArgument::Code(span, v) => {
+                    if code.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        code = Some(v)
+                    }
+                }
+                RawLengthArgument::Severity(span, v) => {
+                    if severity.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        severity = Some(v)
+                    }
+                }
+                RawLengthArgument::Trimmed(span) => {
+                    if trimmed.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        trimmed = Some(span)
+                    }
+                }
+                RawLengthArgument::Split(span, v) => {
+                    if split.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        split = Some(v)
+                    }
+                }
             }
         }
 
@@ -391,12 +554,17 @@ impl Parse for model::RawLength {
         }
 
         Ok(model::RawLength {
-            mode: mode.unwrap_or_default(),
+            mode,
+            trimmed: trimmed.is_some(),
+            split,
             range: model::Range {
                 span,
                 min,
                 max,
                 equal,
+                message,
+                code,
+                severity,
             },
         })
     }
@@ -407,6 +575,11 @@ enum RawLengthArgument {
     Max(Span, model::Either<usize, syn::Expr>),
     Equal(Span, model::Either<usize, syn::Expr>),
     Mode(Span, model::LengthMode),
+    Message(Span, model::Str),
+    Code(Span, model::Str),
+    Severity(Span, model::Str),
+    Trimmed(Span),
+    Split(Span, syn::Expr),
 }
 
 impl Parse for RawLengthArgument {
@@ -419,6 +592,15 @@ impl Parse for RawLengthArgument {
             "chars" => RawLengthArgument::Mode(span, model::LengthMode::Chars),
             "graphemes" => RawLengthArgument::Mode(span, model::LengthMode::Graphemes),
             "utf16" => RawLengthArgument::Mode(span, model::LengthMode::Utf16),
+            "words" => RawLengthArgument::Mode(span, model::LengthMode::Words),
+            "lines" => RawLengthArgument::Mode(span, model::LengthMode::Lines),
+            "count" => RawLengthArgument::Mode(span, model::LengthMode::Count),
+            "base64_decoded" => RawLengthArgument::Mode(span, model::LengthMode::Base64Decoded),
+            "trimmed" => RawLengthArgument::Trimmed(span),
+            "split" => {
+                let _ = input.parse::<Token![=]>()?;
+                RawLengthArgument::Split(span, input.parse::<syn::Expr>()?)
+            }
             "min" => {
                 let _ = input.parse::<Token![=]>()?;
                 let v = input.parse::<syn::Expr>()?;
@@ -434,6 +616,18 @@ impl Parse for RawLengthArgument {
                 let v = input.parse::<syn::Expr>()?;
                 RawLengthArgument::Equal(span, FromExpr::from_expr(v)?)
             }
+            "message" => {
+                let _ = input.parse::<Token![=]>()?;
+                RawLengthArgument::Message(span, input.parse::<model::Str>()?)
+            }
+            "code" => {
+                let _ = input.parse::<Token![=]>()?;
+                RawLengthArgument::Code(span, input.parse::<model::Str>()?)
+            }
+            "severity" => {
+                let _ = input.parse::<Token![=]>()?;
+                RawLengthArgument::Severity(span, input.parse::<model::Str>()?)
+            }
             _ => {
                 if input.peek(Token![=]) {
                     let _ = input.parse::<Token![=]>()?;
@@ -462,6 +656,9 @@ where
         let mut min = None::<T>;
         let mut max = None::<T>;
         let mut equal = None::<T>;
+        let mut message = None::<model::Str>;
+        let mut code = None::<model::Str>;
+        let mut severity = None::<model::Str>;
 
         for pair in pairs {
             if pair.path.is_ident("min") {
@@ -510,6 +707,35 @@ where
                     ));
                 }
                 equal = Some(value);
+            } else if pair.path.is_ident("message")
+                || pair.path.is_ident("code")
+                || pair.path.is_ident("severity")
+            {
+                let slot = if pair.path.is_ident("message") {
+                    &mut message
+                } else if pair.path.is_ident("code") {
+                    &mut code
+                } else {
+                    &mut severity
+                };
+                if slot.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                match pair.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }) => {
+                        *slot = Some(model::Str {
+                            span: lit.span(),
+                            value: lit.value(),
+                        })
+                    }
+                    value => {
+                        error.maybe_fold(syn::Error::new(value.span(), "expected a string literal"))
+                    }
+                }
             } else {
                 error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
                 continue;
@@ -524,6 +750,9 @@ where
                 min,
                 max,
                 equal,
+                message,
+                code,
+                severity,
             })
         }
     }
@@ -17,7 +17,7 @@ use tokio::net::TcpListener;
 #[derive(Debug, Serialize, Deserialize, Validate)]
 #[garde(context(PasswordContext))]
 struct Person {
-    #[garde(ascii, length(min = 3, max = 25))]
+    #[garde(ascii, char_length(min = 3, max = 25))]
     username: String,
     #[garde(custom(password_validation))]
     password: String,
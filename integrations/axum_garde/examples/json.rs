@@ -16,9 +16,9 @@ use tokio::net::TcpListener;
 // Define your valid scheme
 #[derive(Debug, Serialize, Deserialize, Validate)]
 struct Person {
-    #[garde(ascii, length(min = 3, max = 25))]
+    #[garde(ascii, char_length(min = 3, max = 25))]
     username: String,
-    #[garde(length(min = 15))]
+    #[garde(char_length(min = 15))]
     password: String,
 }
 
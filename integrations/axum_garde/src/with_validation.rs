@@ -33,7 +33,7 @@ use super::{IntoInner, WithValidationRejection};
 ///
 /// #[derive(Debug, Serialize, Deserialize, Validate)]
 /// struct Person {
-///     #[garde(length(min = 1, max = 10))]
+///     #[garde(char_length(min = 1, max = 10))]
 ///     name: String
 /// }
 ///
@@ -14,7 +14,7 @@ use speculoos::assert_that;
 
 #[derive(Debug, Serialize, Deserialize, Validate, PartialEq, Eq)]
 struct Person {
-    #[garde(length(min = 1, max = 10))]
+    #[garde(char_length(min = 1, max = 10))]
     name: String,
 }
 
@@ -12,13 +12,14 @@ use thiserror::Error;
 #[derive(Serialize, Deserialize, Validate, Message, Clone)]
 struct Person {
     #[prost(string, tag = "1")]
-    #[garde(length(min = 1))]
+    #[garde(char_length(min = 1))]
     name: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Validate)]
-struct PathTuple(#[garde(length(min = 1))] pub String);
+struct PathTuple(#[garde(char_length(min = 1))] pub String);
 
+#[allow(dead_code)]
 #[derive(Debug, Error)]
 enum CustomRejection {
     #[cfg_attr(feature = "json", error(transparent))]